@@ -100,6 +100,23 @@ impl<T: ViewType + ?Sized> MutableBinaryViewArray<T> {
         }
     }
 
+    /// Create a [`MutableBinaryViewArray`] with room for `capacity` views and `bytes_capacity`
+    /// bytes of non-inlined values in its first buffer.
+    ///
+    /// The bytes reservation is capped at the largest buffer size the array grows to on its own.
+    pub fn with_capacities(capacity: usize, bytes_capacity: usize) -> Self {
+        let mut out = Self::with_capacity(capacity);
+        out.in_progress_buffer = Vec::with_capacity(bytes_capacity.min(MAX_EXP_BLOCK_SIZE));
+        out
+    }
+
+    /// Shrink the views and the buffer that is being written to, so that no unused capacity is
+    /// kept alive once the array is frozen.
+    pub fn shrink_to_fit(&mut self) {
+        self.views.shrink_to_fit();
+        self.in_progress_buffer.shrink_to_fit();
+    }
+
     /// Get a mutable reference to the [`Vec`] of [`View`]s in this [`MutableBinaryViewArray`].
     ///
     /// # Safety
@@ -829,7 +846,7 @@ impl<T: ViewType + ?Sized> MutableArray for MutableBinaryViewArray<T> {
     }
 
     fn shrink_to_fit(&mut self) {
-        self.views.shrink_to_fit()
+        MutableBinaryViewArray::shrink_to_fit(self)
     }
 }
 
@@ -893,6 +910,19 @@ mod tests {
         assert!(roundtrip(&[b"abc", b"def", b"hix", b"xyz"]));
     }
 
+    #[test]
+    fn with_capacities_bounded() {
+        let mut bv = MutableBinaryViewArray::<[u8]>::with_capacities(1, usize::MAX);
+        assert_eq!(bv.in_progress_buffer.capacity(), MAX_EXP_BLOCK_SIZE);
+
+        bv.push_value(b"a_very_very_long_string_that_is_not_inlinable");
+        bv.shrink_to_fit();
+        assert_eq!(
+            bv.in_progress_buffer.capacity(),
+            bv.in_progress_buffer.len()
+        );
+    }
+
     #[test]
     fn extend_with_not_inlinable_fastpath() {
         assert!(roundtrip(&[
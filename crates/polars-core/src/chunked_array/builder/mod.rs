@@ -194,6 +194,27 @@ mod test {
         assert_eq!(Vec::from(&ca), values);
     }
 
+    #[test]
+    fn test_string_builder_with_capacities() {
+        let values = [
+            Some("a"),
+            None,
+            Some("a string that is too long to be inlined"),
+            Some("another string that does not fit in the bytes capacity"),
+        ];
+        let ca = StringChunked::from_iter_with_capacity(
+            PlSmallStr::from_static("foo"),
+            values,
+            values.len(),
+            16,
+        );
+        assert_eq!(Vec::from(&ca), values);
+
+        let mut builder = StringChunkedBuilder::with_capacities(PlSmallStr::EMPTY, 0, 0);
+        builder.append_value(values[2].unwrap());
+        assert_eq!(builder.finish().get(0), values[2]);
+    }
+
     #[test]
     fn test_list_builder() {
         let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new(
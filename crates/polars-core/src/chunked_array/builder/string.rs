@@ -30,6 +30,19 @@ impl<T: ViewType + ?Sized> BinViewChunkedBuilder<T> {
         }
     }
 
+    /// Create a new BinViewChunkedBuilder that also reserves room for the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of string elements in the final array.
+    /// * `bytes_capacity` - Total number of bytes of the string elements.
+    pub fn with_capacities(name: PlSmallStr, capacity: usize, bytes_capacity: usize) -> Self {
+        Self {
+            chunk_builder: MutableBinaryViewArray::with_capacities(capacity, bytes_capacity),
+            field: Arc::new(Field::new(name, DataType::from_arrow_dtype(&T::DATA_TYPE))),
+        }
+    }
+
     /// Appends a value of type `T` into the builder
     #[inline]
     pub fn append_value<S: AsRef<T>>(&mut self, v: S) {
@@ -54,6 +67,27 @@ impl StringChunkedBuilder {
         ChunkedArray::new_with_compute_len(self.field, vec![arr])
     }
 }
+
+impl StringChunked {
+    /// Create a [`StringChunked`] from an iterator, sizing the builder up front with the number
+    /// of elements and the total number of bytes they hold.
+    pub fn from_iter_with_capacity<I, S>(
+        name: PlSmallStr,
+        iter: I,
+        capacity: usize,
+        bytes_capacity: usize,
+    ) -> Self
+    where
+        I: IntoIterator<Item = Option<S>>,
+        S: AsRef<str>,
+    {
+        let mut builder = StringChunkedBuilder::with_capacities(name, capacity, bytes_capacity);
+        for opt in iter {
+            builder.append_option(opt);
+        }
+        builder.finish()
+    }
+}
 impl BinaryChunkedBuilder {
     pub fn finish(mut self) -> BinaryChunked {
         let arr = self.chunk_builder.as_box();
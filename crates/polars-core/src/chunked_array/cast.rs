@@ -727,4 +727,47 @@ mod test {
         let out = out.cast(&DataType::from_categories(cats)).unwrap();
         assert!(matches!(out.dtype(), &DataType::Categorical(_, _)))
     }
+
+    #[test]
+    fn test_strict_cast_failures() -> PolarsResult<()> {
+        let s = Series::new(PlSmallStr::from_static("a"), &["1", "abc", "3"]);
+        let out = s.cast(&DataType::Int32)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, Some(3)]);
+        let err = s.strict_cast(&DataType::Int32).unwrap_err().to_string();
+        assert!(err.contains(r#"["abc"]"#), "{err}");
+        assert!(err.contains("first failure at row 1"), "{err}");
+
+        let s = Series::new(PlSmallStr::from_static("a"), &[1i64, 2, 300, -500]);
+        let out = s.cast(&DataType::Int8)?;
+        assert_eq!(Vec::from(out.i8()?), &[Some(1), Some(2), None, None]);
+        let err = s.strict_cast(&DataType::Int8).unwrap_err().to_string();
+        assert!(err.contains("for 2 out of 4 values"), "{err}");
+        assert!(err.contains("first failure at row 2"), "{err}");
+
+        // Fractional parts are truncated in both modes, only out of range values fail.
+        let s = Series::new(PlSmallStr::from_static("a"), &[2.7f64, -2.7, 1e20]);
+        let out = s.cast(&DataType::Int32)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), Some(-2), None]);
+        assert!(s.slice(0, 2).strict_cast(&DataType::Int32).is_ok());
+        assert!(s.strict_cast(&DataType::Int32).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn test_cast_datetime_to_date_truncates() -> PolarsResult<()> {
+        let ms_per_day = 86_400_000i64;
+        let ca = Int64Chunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some(0), Some(ms_per_day + ms_per_day / 2), None],
+        );
+        let s = ca.into_datetime(TimeUnit::Milliseconds, None).into_series();
+        let out = s.strict_cast(&DataType::Date)?;
+        assert_eq!(out.dtype(), &DataType::Date);
+        assert_eq!(
+            Vec::from(out.to_physical_repr().i32()?),
+            &[Some(0), Some(1), None]
+        );
+        Ok(())
+    }
 }
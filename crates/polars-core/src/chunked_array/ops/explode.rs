@@ -223,9 +223,17 @@ impl ExplodeByOffsets for BooleanChunked {
 }
 
 /// Convert Arrow array offsets to indexes of the original list
-pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<IdxSize> {
+///
+/// An empty (or null) list yields a single index, matching the single null row that
+/// `explode(skip_empty = false)` emits for such a list. This keeps the other columns of a
+/// `DataFrame` aligned with the exploded column.
+///
+/// Errors if `capacity` exceeds the number of rows addressable by [`IdxSize`].
+pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> PolarsResult<Vec<IdxSize>> {
+    IdxSize::try_from(capacity)
+        .map_err(|_| polars_err!(bigidx, ctx = "explode", size = capacity))?;
     if offsets.is_empty() {
-        return vec![];
+        return Ok(vec![]);
     }
 
     let mut idx = Vec::with_capacity(capacity);
@@ -257,7 +265,7 @@ pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<IdxSiz
         idx.push(last_idx);
     }
     idx.truncate(capacity);
-    idx
+    Ok(idx)
 }
 
 #[cfg(test)]
@@ -295,6 +303,68 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_explode_list_chunks_without_copy() -> PolarsResult<()> {
+        let list = |values: &[&[i32]]| {
+            let mut builder =
+                get_list_builder(&DataType::Int32, 5, 5, PlSmallStr::from_static("a"));
+            for v in values {
+                builder
+                    .append_series(&Series::new(PlSmallStr::EMPTY, *v))
+                    .unwrap();
+            }
+            builder.finish()
+        };
+        let mut ca = list(&[&[1, 2], &[3]]);
+        ca.append(&list(&[&[4, 5, 6], &[7]]))?;
+        assert_eq!(ca.chunks().len(), 2);
+        assert!(ca._can_fast_explode());
+
+        // Every chunk is exploded on its own and reuses the values buffer of its list array.
+        let (exploded, offsets) = ca.explode_and_offsets(false)?;
+        assert_eq!(offsets.as_slice(), &[0, 2, 3, 6, 7]);
+        assert_eq!(ca.offsets()?.as_slice(), offsets.as_slice());
+        let exploded = exploded.i32()?;
+        assert_eq!(exploded.chunks().len(), 2);
+        for (listarr, values) in ca.downcast_iter().zip(exploded.downcast_iter()) {
+            let list_values = listarr
+                .values()
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            assert_eq!(list_values.values().as_ptr(), values.values().as_ptr());
+        }
+        assert_eq!(
+            exploded.into_no_null_iter().collect::<Vec<_>>(),
+            &[1, 2, 3, 4, 5, 6, 7]
+        );
+
+        // A single chunk is exploded without a copy as well.
+        let single = list(&[&[1, 2], &[3]]);
+        let (exploded, _) = single.explode_and_offsets(false)?;
+        let list_values = single.downcast_iter().next().unwrap().values();
+        let list_values = list_values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap();
+        let exploded = exploded.i32()?;
+        assert_eq!(exploded.chunks().len(), 1);
+        assert_eq!(
+            list_values.values().as_ptr(),
+            exploded.downcast_iter().next().unwrap().values().as_ptr()
+        );
+
+        // A slice spanning the chunk boundary.
+        let sliced = ca.slice(1, 2);
+        let (exploded, offsets) = sliced.explode_and_offsets(false)?;
+        assert_eq!(offsets.as_slice(), &[0, 1, 4]);
+        assert_eq!(
+            exploded.i32()?.into_no_null_iter().collect::<Vec<_>>(),
+            &[3, 4, 5, 6]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_explode_empty_list_slot() -> PolarsResult<()> {
         // primitive
@@ -418,29 +488,46 @@ mod test {
     #[test]
     fn test_row_offsets() {
         let offsets = &[0, 1, 2, 2, 3, 4, 4];
-        let out = offsets_to_indexes(offsets, 6);
+        let out = offsets_to_indexes(offsets, 6).unwrap();
         assert_eq!(out, &[0, 1, 2, 3, 4, 5]);
     }
 
     #[test]
     fn test_empty_row_offsets() {
         let offsets = &[0, 0];
-        let out = offsets_to_indexes(offsets, 0);
+        let out = offsets_to_indexes(offsets, 0).unwrap();
         let expected: Vec<IdxSize> = Vec::new();
         assert_eq!(out, expected);
     }
 
+    #[test]
+    fn test_row_offsets_empty_and_null_lists() {
+        // Empty lists at the start, in the middle and at the end.
+        let offsets = &[0, 0, 2, 2, 3, 3];
+        let out = offsets_to_indexes(offsets, 6).unwrap();
+        assert_eq!(out, &[0, 1, 1, 2, 3, 4]);
+    }
+
     #[test]
     fn test_row_offsets_over_capacity() {
         let offsets = &[0, 1, 1, 2, 2];
-        let out = offsets_to_indexes(offsets, 2);
+        let out = offsets_to_indexes(offsets, 2).unwrap();
         assert_eq!(out, &[0, 1]);
     }
 
     #[test]
     fn test_row_offsets_nonzero_first_offset() {
         let offsets = &[3, 6, 8];
-        let out = offsets_to_indexes(offsets, 10);
+        let out = offsets_to_indexes(offsets, 10).unwrap();
         assert_eq!(out, &[0, 0, 0, 1, 1, 2, 2, 2, 2, 2]);
     }
+
+    #[test]
+    #[cfg(not(feature = "bigidx"))]
+    fn test_row_offsets_idx_overflow() {
+        // Offsets just past the `IdxSize` boundary must error before allocating.
+        let offsets = &[0, IdxSize::MAX as i64 - 1, IdxSize::MAX as i64 + 1];
+        let out = offsets_to_indexes(offsets, IdxSize::MAX as usize + 1);
+        assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+    }
 }
@@ -45,10 +45,55 @@ impl ListChunked {
 
         (values, offsets_buf)
     }
+
+    /// Concatenate the offsets of all chunks, rebased so that they index into the values of the
+    /// chunks laid out one after another.
+    fn concat_offsets(&self) -> OffsetsBuffer<i64> {
+        let mut new_offsets = Vec::with_capacity(self.len() + 1);
+        new_offsets.push(0i64);
+        let mut base = 0i64;
+        for listarr in self.downcast_iter() {
+            let offsets = listarr.offsets().as_slice();
+            let start = offsets[0];
+            new_offsets.extend(offsets[1..].iter().map(|&o| o - start + base));
+            base += offsets[offsets.len() - 1] - start;
+        }
+        // SAFETY: monotonically increasing
+        unsafe { OffsetsBuffer::new_unchecked(new_offsets.into()) }
+    }
+
+    /// Fast explode every chunk on its own, so that the values of a multi-chunk array don't have
+    /// to be copied into one contiguous buffer first.
+    fn explode_chunks_fast(&self) -> (Series, OffsetsBuffer<i64>) {
+        let values = self
+            .downcast_iter()
+            .map(|listarr| {
+                let offsets = listarr.offsets();
+                let start = *offsets.first() as usize;
+                let len = *offsets.last() as usize - start;
+                // SAFETY: we are in bounds
+                unsafe { listarr.values().sliced_unchecked(start, len) }
+            })
+            .collect();
+        // SAFETY: inner_dtype should be correct
+        let s = unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                self.name().clone(),
+                values,
+                &self.inner_dtype().to_physical(),
+            )
+        };
+        // restore logical type
+        let s = unsafe { s.from_physical_unchecked(self.inner_dtype()) }.unwrap();
+        (s, self.concat_offsets())
+    }
 }
 
 impl ChunkExplode for ListChunked {
     fn offsets(&self) -> PolarsResult<OffsetsBuffer<i64>> {
+        if self.chunks.len() > 1 {
+            return Ok(self.concat_offsets());
+        }
         let ca = self.rechunk();
         let listarr: &LargeListArray = ca.downcast_iter().next().unwrap();
         let offsets = listarr.offsets().clone();
@@ -60,6 +105,9 @@ impl ChunkExplode for ListChunked {
         // values array of the list. And we also return a slice of the offsets. This slice can be
         // used to find the old list layout or indexes to expand a DataFrame in the same manner as
         // the `explode` operation.
+        if self.chunks.len() > 1 && self._can_fast_explode() {
+            return Ok(self.explode_chunks_fast());
+        }
         let ca = self.rechunk();
         let listarr: &LargeListArray = ca.downcast_iter().next().unwrap();
         let offsets_buf = listarr.offsets().clone();
@@ -380,3 +380,62 @@ impl ChunkFillNullValue<&[u8]> for BinaryChunked {
         self.set(&self.is_null(), Some(value))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_fill_null_across_chunks() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new("a".into(), &[Some(1), None]);
+        ca.append(&Int32Chunked::new("a".into(), &[None, Some(4), None]))?;
+        assert_eq!(ca.chunks().len(), 2);
+        let s = ca.into_series();
+
+        let out = s.fill_null(FillNullStrategy::Forward(None))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(1), Some(1), Some(4), Some(4)]
+        );
+        let out = s.fill_null(FillNullStrategy::Forward(Some(1)))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(1), None, Some(4), Some(4)]
+        );
+        let out = s.fill_null(FillNullStrategy::Backward(None))?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(4), Some(4), Some(4), None]
+        );
+
+        let mut ca = StringChunked::new("b".into(), &[Some("x"), None]);
+        ca.append(&StringChunked::new("b".into(), &[None, Some("y")]))?;
+        let out = ca
+            .into_series()
+            .fill_null(FillNullStrategy::Forward(None))?;
+        assert_eq!(
+            Vec::from(out.str()?),
+            &[Some("x"), Some("x"), Some("x"), Some("y")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_df() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1i32), None, Some(3)],
+            "b" => [None, Some(2.0f64), Some(4.0)],
+        ]?;
+
+        let out = df.fill_null(FillNullStrategy::Mean)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.f64()?),
+            &[Some(3.0), Some(2.0), Some(4.0)]
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use polars_compute::filter::filter as filter_fn;
 
 #[cfg(feature = "object")]
@@ -27,10 +29,20 @@ where
             };
         }
         check_filter_len!(self, filter);
+        // Align the mask with the chunks of `self` instead of the other way around, so the values
+        // of `self` are not copied into one contiguous buffer first.
+        let filter = if self.chunks.len() > 1
+            && filter.chunks.len() > 1
+            && !self.chunk_lengths().eq(filter.chunk_lengths())
+        {
+            Cow::Owned(filter.rechunk().match_chunks(self.chunk_lengths()))
+        } else {
+            Cow::Borrowed(filter)
+        };
         Ok(unsafe {
             arity::binary_unchecked_same_type(
                 self,
-                filter,
+                &filter,
                 |left, mask| filter_fn(left, mask),
                 true,
                 true,
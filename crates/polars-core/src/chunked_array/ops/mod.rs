@@ -82,6 +82,9 @@ pub trait ChunkAnyValue {
 }
 
 /// Explode/flatten a List or String Series
+///
+/// Empty and null lists produce a single null row, unless `skip_empty` is set, in which case
+/// empty lists produce no rows at all.
 pub trait ChunkExplode {
     fn explode(&self, skip_empty: bool) -> PolarsResult<Series> {
         self.explode_and_offsets(skip_empty).map(|t| t.0)
@@ -406,9 +409,9 @@ pub type FillNullLimit = Option<IdxSize>;
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum FillNullStrategy {
-    /// previous value in array
-    Backward(FillNullLimit),
     /// next value in array
+    Backward(FillNullLimit),
+    /// previous value in array
     Forward(FillNullLimit),
     /// mean value of array
     Mean,
@@ -1046,6 +1046,43 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sort_multiple_nulls_last_stable() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [Some(1), None, Some(1), None, Some(2)],
+            "b" => [Some("x"), Some("y"), None, Some("x"), Some("z")],
+            "idx" => [0, 1, 2, 3, 4]
+        )?;
+
+        let out = df.sort(
+            ["a", "b"],
+            SortMultipleOptions::default()
+                .with_order_descending_multi([false, true])
+                .with_nulls_last_multi([true, false])
+                .with_maintain_order(true),
+        )?;
+        assert_eq!(
+            Vec::from(out.column("idx")?.i32()?),
+            &[Some(2), Some(0), Some(4), Some(1), Some(3)]
+        );
+
+        // ties keep their input order
+        let df = df!(
+            "a" => [2, 1, 2, 1],
+            "idx" => [0, 1, 2, 3]
+        )?;
+        let out = df.sort(
+            ["a"],
+            SortMultipleOptions::default().with_maintain_order(true),
+        )?;
+        assert_eq!(
+            Vec::from(out.column("idx")?.i32()?),
+            &[Some(1), Some(3), Some(0), Some(2)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_sort_string() {
         let ca = StringChunked::new(
@@ -1106,4 +1143,55 @@ mod test {
         let expected = &[Some("c"), Some("b"), Some("a")];
         assert_eq!(Vec::from(&out), expected);
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sort_multithreaded_matches_single_threaded() -> PolarsResult<()> {
+        let n = 10_000;
+        let a = Int64Chunked::from_iter(
+            (0..n).map(|i| (i % 7 != 0).then_some((i * 7919 % 101) as i64)),
+        )
+        .with_name(PlSmallStr::from_static("a"));
+        let b = StringChunked::from_iter_values(
+            PlSmallStr::from_static("b"),
+            (0..n).map(|i| ["x", "y", "z"][i % 3]),
+        );
+
+        for descending in [false, true] {
+            for nulls_last in [false, true] {
+                let options = SortOptions {
+                    descending,
+                    nulls_last,
+                    multithreaded: false,
+                    maintain_order: true,
+                    limit: None,
+                };
+                let parallel = SortOptions {
+                    multithreaded: true,
+                    ..options
+                };
+                assert!(
+                    a.sort_with(options)
+                        .into_series()
+                        .equals_missing(&a.sort_with(parallel).into_series())
+                );
+                assert_eq!(
+                    Vec::from(&a.arg_sort(options)),
+                    Vec::from(&a.arg_sort(parallel))
+                );
+            }
+        }
+
+        // Ties on the first key keep their original order in both paths.
+        let df = DataFrame::new(vec![a.into_column(), b.into_column()])?;
+        let options = SortMultipleOptions::default()
+            .with_order_descending_multi([true, false])
+            .with_nulls_last(true)
+            .with_maintain_order(true);
+        let single = df.sort(["b", "a"], options.clone().with_multithreaded(false))?;
+        let parallel = df.sort(["b", "a"], options.with_multithreaded(true))?;
+        assert!(single.equals_missing(&parallel));
+        assert_eq!(single.column("b")?.str()?.get(0), Some("z"));
+        Ok(())
+    }
 }
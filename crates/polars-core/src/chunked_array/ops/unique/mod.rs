@@ -301,4 +301,26 @@ mod test {
             vec![Some(0), Some(1), Some(4)]
         );
     }
+
+    #[test]
+    fn arg_unique_strings_first_occurrence() -> PolarsResult<()> {
+        let mut ca = StringChunked::new(PlSmallStr::EMPTY, &[Some("b"), None, Some("a")]);
+        ca.append(&StringChunked::new(
+            PlSmallStr::EMPTY,
+            &[Some("b"), Some("c"), None, Some("a")],
+        ))?;
+
+        assert_eq!(
+            Vec::from(&ca.arg_unique()?),
+            &[Some(0), Some(1), Some(2), Some(4)]
+        );
+
+        // `unique_stable` keeps the order in which values first appear.
+        let out = ca.into_series().unique_stable()?;
+        assert_eq!(
+            Vec::from(out.str()?),
+            &[Some("b"), None, Some("a"), Some("c")]
+        );
+        Ok(())
+    }
 }
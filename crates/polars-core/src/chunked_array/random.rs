@@ -78,6 +78,7 @@ fn ensure_shape(n: usize, len: usize, with_replacement: bool) -> PolarsResult<()
 }
 
 impl Series {
+    /// Sample n datapoints from this [`Series`].
     pub fn sample_n(
         &self,
         n: usize,
@@ -391,4 +392,35 @@ mod test {
             .is_ok()
         );
     }
+
+    #[test]
+    fn test_sample_seeded_aligned() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..100).collect::<Vec<i32>>(),
+            "b" => (0..100).map(|v| v * 10).collect::<Vec<i32>>()
+        ]?;
+
+        for with_replacement in [false, true] {
+            let out = df.sample_n_literal(20, with_replacement, true, Some(42))?;
+            assert_eq!(out.height(), 20);
+            // A fixed seed gives the same sample.
+            assert!(out.equals(&df.sample_n_literal(20, with_replacement, true, Some(42))?));
+            // All columns are gathered with the same indices.
+            let a = out.column("a")?.i32()?;
+            let b = out.column("b")?.i32()?;
+            assert!(
+                a.into_iter()
+                    .zip(b)
+                    .all(|(a, b)| a.unwrap() * 10 == b.unwrap())
+            );
+        }
+
+        let s = df.column("a")?.as_materialized_series();
+        let out = s.sample_n(100, false, true, Some(0))?;
+        let mut values: Vec<_> = out.i32()?.into_no_null_iter().collect();
+        values.sort_unstable();
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+        assert!(s.sample_n(101, false, false, Some(0)).is_err());
+        Ok(())
+    }
 }
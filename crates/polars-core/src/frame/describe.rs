@@ -0,0 +1,122 @@
+use polars_utils::format_pl_smallstr;
+
+use super::*;
+
+impl DataFrame {
+    /// Summary statistics of this [`DataFrame`].
+    ///
+    /// The output has a `statistic` column with the names of the statistics
+    /// (`count`, `null_count`, `mean`, `std`, `min`, the requested percentiles and `max`)
+    /// and a `Float64` column for every column of `self`. The percentiles default to
+    /// `[0.25, 0.5, 0.75]` and use the nearest quantile method.
+    ///
+    /// Non-numeric columns only have a `count` and `null_count`. Statistics that cannot
+    /// be computed, e.g. on an all-null column, are null.
+    pub fn describe(&self, percentiles: Option<&[f64]>) -> PolarsResult<DataFrame> {
+        let percentiles = percentiles.unwrap_or(&[0.25, 0.5, 0.75]);
+        for &p in percentiles {
+            polars_ensure!(
+                (0.0..=1.0).contains(&p),
+                ComputeError: "percentiles must all be in the range [0, 1], got {}", p
+            );
+        }
+
+        let mut statistics: Vec<PlSmallStr> = ["count", "null_count", "mean", "std", "min"]
+            .into_iter()
+            .map(PlSmallStr::from_static)
+            .collect();
+        statistics.extend(
+            percentiles
+                .iter()
+                .map(|p| format_pl_smallstr!("{}%", p * 100.0)),
+        );
+        statistics.push(PlSmallStr::from_static("max"));
+
+        let mut columns = Vec::with_capacity(self.width() + 1);
+        columns.push(
+            StringChunked::from_iter_values(
+                PlSmallStr::from_static("statistic"),
+                statistics.iter().map(|s| s.as_str()),
+            )
+            .into_column(),
+        );
+
+        for c in self.get_columns() {
+            let s = c.as_materialized_series();
+            let null_count = s.null_count();
+
+            let mut values = Vec::with_capacity(statistics.len());
+            values.push(Some((s.len() - null_count) as f64));
+            values.push(Some(null_count as f64));
+            if s.dtype().is_primitive_numeric() {
+                values.push(s.mean());
+                values.push(s.std(1));
+                values.push(s.min::<f64>()?);
+                for &p in percentiles {
+                    let q = s.quantile_reduce(p, QuantileMethod::Nearest)?;
+                    values.push(q.value().extract::<f64>());
+                }
+                values.push(s.max::<f64>()?);
+            } else {
+                values.resize(statistics.len(), None);
+            }
+
+            columns.push(
+                Float64Chunked::from_iter(values)
+                    .with_name(c.name().clone())
+                    .into_column(),
+            );
+        }
+
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_describe() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [Some(1), Some(2), None, Some(4), Some(5)],
+            "b" => [None::<f64>, None, None, None, None],
+            "c" => ["x", "y", "z", "x", "y"]
+        }?;
+
+        let out = df.describe(None)?;
+        assert_eq!(out.shape(), (9, 4));
+        assert_eq!(
+            Vec::from(out.column("statistic")?.str()?),
+            &[
+                Some("count"),
+                Some("null_count"),
+                Some("mean"),
+                Some("std"),
+                Some("min"),
+                Some("25%"),
+                Some("50%"),
+                Some("75%"),
+                Some("max")
+            ]
+        );
+
+        let a = Vec::from(out.column("a")?.f64()?);
+        assert_eq!(&a[..3], &[Some(4.0), Some(1.0), Some(3.0)]);
+        assert_eq!(a[4], Some(1.0));
+        assert_eq!(a[8], Some(5.0));
+
+        // All-null columns get null statistics.
+        let b = Vec::from(out.column("b")?.f64()?);
+        assert_eq!(&b[..2], &[Some(0.0), Some(5.0)]);
+        assert!(b[2..].iter().all(|v| v.is_none()));
+
+        // Non-numeric columns only get counts.
+        let c = Vec::from(out.column("c")?.f64()?);
+        assert_eq!(&c[..2], &[Some(5.0), Some(0.0)]);
+        assert!(c[2..].iter().all(|v| v.is_none()));
+
+        assert!(df.describe(Some(&[1.5])).is_err());
+        Ok(())
+    }
+}
@@ -74,7 +74,7 @@ impl DataFrame {
             } else {
                 polars_bail!(
                     ShapeMismatch: "exploded column(s) {:?} doesn't have the same length: {} \
-                    as the dataframe: {}", exploded.name(), exploded.name(), df.height(),
+                    as the dataframe: {}", exploded.name(), exploded.len(), df.height(),
                 );
             }
             Ok(())
@@ -87,7 +87,7 @@ impl DataFrame {
 
                 let offset_l = first_offsets[0];
                 let offset_r = offsets[0];
-                let all_equal_len = first_offsets.len() != offsets.len() || {
+                let all_equal_len = first_offsets.len() == offsets.len() && {
                     first_offsets
                         .iter()
                         .zip(offsets.iter())
@@ -103,7 +103,7 @@ impl DataFrame {
         let process_first = || {
             let (exploded, offsets) = &exploded_columns[0];
 
-            let row_idx = offsets_to_indexes(offsets.as_slice(), exploded.len());
+            let row_idx = offsets_to_indexes(offsets.as_slice(), exploded.len())?;
             let mut row_idx = IdxCa::from_vec(PlSmallStr::EMPTY, row_idx);
             row_idx.set_sorted_flag(IsSorted::Ascending);
 
@@ -196,6 +196,7 @@ impl DataFrame {
 
 #[cfg(test)]
 mod test {
+    use crate::chunked_array::builder::get_list_builder;
     use crate::prelude::*;
 
     #[test]
@@ -286,6 +287,29 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_df_empty_and_null_lists() -> PolarsResult<()> {
+        let mut builder = get_list_builder(&DataType::Int32, 8, 4, PlSmallStr::from_static("foo"));
+        builder.append_series(&Series::new(PlSmallStr::EMPTY, &[1i32, 2]))?;
+        builder.append_null();
+        builder.append_series(&Series::new_empty(PlSmallStr::EMPTY, &DataType::Int32))?;
+        builder.append_series(&Series::new(PlSmallStr::EMPTY, &[3i32]))?;
+        builder.append_series(&Series::new_empty(PlSmallStr::EMPTY, &DataType::Int32))?;
+        let list = builder.finish().into_column();
+
+        let b = Column::new(PlSmallStr::from_static("B"), [1i32, 2, 3, 4, 5]);
+        let df = DataFrame::new(vec![list, b])?;
+
+        let out = df.explode(["foo"])?;
+        let expected = df![
+            "foo" => [Some(1i32), Some(2), None, None, Some(3), None],
+            "B" => [1i32, 1, 2, 3, 4, 5],
+        ]?;
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_explode_single_col() -> PolarsResult<()> {
@@ -305,4 +329,51 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_multiple_cols() -> PolarsResult<()> {
+        let a = Column::new(
+            PlSmallStr::from_static("a"),
+            [
+                Series::new(PlSmallStr::EMPTY, &[1i32, 2]),
+                Series::new_empty(PlSmallStr::EMPTY, &DataType::Int32),
+                Series::new(PlSmallStr::EMPTY, &[3i32]),
+            ],
+        );
+        let b = Column::new(
+            PlSmallStr::from_static("b"),
+            [
+                Series::new(PlSmallStr::EMPTY, &["x", "y"]),
+                Series::new_empty(PlSmallStr::EMPTY, &DataType::String),
+                Series::new(PlSmallStr::EMPTY, &["z"]),
+            ],
+        );
+        let c = Column::new(PlSmallStr::from_static("c"), [1i32, 2, 3]);
+        let df = DataFrame::new(vec![a, c, b])?;
+
+        // Empty lists are kept as a single null row in every exploded column.
+        let out = df.explode(["a", "b"])?;
+        let expected = df![
+            "a" => [Some(1i32), Some(2), None, Some(3)],
+            "c" => [1i32, 1, 2, 3],
+            "b" => [Some("x"), Some("y"), None, Some("z")],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        let d = Column::new(
+            PlSmallStr::from_static("d"),
+            [
+                Series::new(PlSmallStr::EMPTY, &[1i32]),
+                Series::new(PlSmallStr::EMPTY, &[2i32]),
+                Series::new(PlSmallStr::EMPTY, &[3i32]),
+            ],
+        );
+        let df = df.hstack(&[d])?;
+        assert!(matches!(
+            df.explode(["a", "d"]),
+            Err(PolarsError::ShapeMismatch(_))
+        ));
+        Ok(())
+    }
 }
@@ -1192,6 +1192,96 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[allow(deprecated)]
+    fn test_group_by_quantile_median_std() -> PolarsResult<()> {
+        // a regular group, a single element group and an all-null group
+        let df = df![
+            "g" => ["a", "a", "b", "a", "c", "a", "c"],
+            "v" => [Some(1), Some(2), Some(5), Some(3), None, Some(4), None]
+        ]?;
+        let gb = df.group_by_stable(["g"])?;
+
+        for (method, a) in [
+            (QuantileMethod::Nearest, 3.0),
+            (QuantileMethod::Lower, 3.0),
+            (QuantileMethod::Higher, 4.0),
+            (QuantileMethod::Midpoint, 3.5),
+            (QuantileMethod::Linear, 3.25),
+        ] {
+            let out = gb.select(["v"]).quantile(0.75, method)?;
+            assert_eq!(
+                Vec::from(out.column("v_quantile_0.75")?.f64()?),
+                &[Some(a), Some(5.0), None]
+            );
+        }
+        assert!(
+            gb.select(["v"])
+                .quantile(1.5, QuantileMethod::Linear)
+                .is_err()
+        );
+
+        let out = gb.select(["v"]).median()?;
+        assert_eq!(
+            Vec::from(out.column("v_median")?.f64()?),
+            &[Some(2.5), Some(5.0), None]
+        );
+
+        let out = gb.select(["v"]).std(0)?;
+        let std = Vec::from(out.column("v_agg_std")?.f64()?);
+        assert!((std[0].unwrap() - 1.25f64.sqrt()).abs() < 1e-9);
+        assert_eq!(&std[1..], &[Some(0.0), None]);
+
+        // A single value has no sample variance.
+        let out = gb.select(["v"]).var(1)?;
+        let var = Vec::from(out.column("v_agg_var")?.f64()?);
+        assert!((var[0].unwrap() - 5.0 / 3.0).abs() < 1e-9);
+        assert_eq!(&var[1..], &[None, None]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_apply() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "c"],
+            "v" => [1, 2, 3, 4, 5]
+        ]?;
+        let gb = df.group_by_stable(["g"])?;
+
+        // The keys are part of every sub-frame.
+        let f = |sub_df: DataFrame| {
+            let g = sub_df.column("g")?.head(Some(1));
+            let v = sub_df.column("v")?.as_materialized_series().sum::<i32>()?;
+            DataFrame::new(vec![g, Column::new("total".into(), [v])])
+        };
+        let out = gb.apply(f)?;
+        assert_eq!(
+            Vec::from(out.column("g")?.str()?),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(out.column("total")?.i32()?),
+            &[Some(4), Some(6), Some(5)]
+        );
+
+        #[allow(deprecated)]
+        let par_out = gb.par_apply(f)?;
+        assert!(par_out.equals(&out));
+
+        // The returned frames must agree on their schema.
+        let out = gb.apply(|sub_df| {
+            if sub_df.column("g")?.str()?.get(0) == Some("b") {
+                sub_df.select(["v"])
+            } else {
+                sub_df.select(["g"])
+            }
+        });
+        assert!(out.is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[cfg(feature = "dtype-categorical")]
@@ -1212,4 +1302,31 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_multiple_keys_no_collisions() -> PolarsResult<()> {
+        // Keys that would collide if the columns were naively concatenated.
+        let df = df![
+            "a" => [Some("ab"), Some("a"), Some("ab"), None, Some(""), Some("a")],
+            "b" => [Some("c"), Some("bc"), Some("c"), Some("c"), Some("c"), Some("bc")],
+            "c" => [Some(0), Some(0), Some(0), None, None, Some(0)],
+        ]?;
+        let keys = df.select_columns(["a", "b", "c"])?;
+
+        for multithreaded in [true, false] {
+            let gb = df.group_by_with_series(keys.clone(), multithreaded, true)?;
+            let mut firsts = gb
+                .get_groups()
+                .iter()
+                .map(|g| match g {
+                    GroupsIndicator::Idx((first, idx)) => (first, idx.len()),
+                    GroupsIndicator::Slice([first, len]) => (first, len as usize),
+                })
+                .collect::<Vec<_>>();
+            firsts.sort_unstable();
+            assert_eq!(firsts, &[(0, 2), (1, 2), (3, 1), (4, 1)]);
+        }
+        Ok(())
+    }
 }
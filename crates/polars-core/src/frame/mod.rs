@@ -24,6 +24,8 @@ pub mod builder;
 mod chunks;
 pub use chunks::chunk_df_for_writing;
 pub mod column;
+#[cfg(feature = "describe")]
+mod describe;
 pub mod explode;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
@@ -2963,6 +2965,8 @@ impl DataFrame {
     /// * Mean fill (replace None with the mean of the whole array)
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
+    /// * Zero fill (replace None with the value zero)
+    /// * One fill (replace None with the value one)
     ///
     /// See the method on [Series](crate::series::Series::fill_null) for more info on the `fill_null` operation.
     pub fn fill_null(&self, strategy: FillNullStrategy) -> PolarsResult<Self> {
@@ -3045,7 +3049,7 @@ impl DataFrame {
 
     /// Unstable distinct. See [`DataFrame::unique_stable`].
     #[cfg(feature = "algorithm_group_by")]
-    pub fn unique<I, S>(
+    pub fn unique(
         &self,
         subset: Option<&[String]>,
         keep: UniqueKeepStrategy,
@@ -3607,6 +3611,38 @@ mod test {
         assert_eq!(new.len(), 0);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_filter_list_col_keeps_chunks() -> PolarsResult<()> {
+        let list = |values: &[&[i32]]| -> ListChunked {
+            values
+                .iter()
+                .map(|v| Series::new(PlSmallStr::EMPTY, *v))
+                .collect()
+        };
+        let mut ll = list(&[&[1], &[2, 3]]);
+        ll.append(&list(&[&[4], &[5, 6]]))?;
+
+        let mut mask = BooleanChunked::from_slice(PlSmallStr::EMPTY, &[true]);
+        mask.append(&BooleanChunked::from_slice(PlSmallStr::EMPTY, &[false]))?;
+        mask.append(&BooleanChunked::from_slice(
+            PlSmallStr::EMPTY,
+            &[true, true],
+        ))?;
+
+        // The mask is aligned with the list, the list is not rechunked to match the mask.
+        let new = ll.filter(&mask)?;
+        assert_eq!(new.chunks.len(), 2);
+        assert_eq!(
+            new.explode(false)?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[1, 4, 5, 6]
+        );
+        Ok(())
+    }
+
     #[test]
     fn slice() {
         let df = create_frame();
@@ -3614,6 +3650,48 @@ mod test {
         assert_eq!(sliced_df.shape(), (2, 2));
     }
 
+    #[test]
+    fn test_slice_views_chunks() -> PolarsResult<()> {
+        let mut df = df!(
+            "a" => [Some(1), None, Some(3), Some(4)],
+            "b" => ["a", "b", "c", "d"],
+            "mask" => [true, false, true, true]
+        )?;
+        df.vstack_mut(&df.clone())?;
+        assert_eq!(df.first_col_n_chunks(), 2);
+
+        // The slice spans both chunks and borrows the values of the original arrays.
+        let sliced = df.slice(2, 4);
+        let a = sliced.column("a")?.i32()?;
+        assert_eq!(a.chunks().len(), 2);
+        let original = df.column("a")?.i32()?;
+        let (first, second) = (
+            original.downcast_get(0).unwrap(),
+            original.downcast_get(1).unwrap(),
+        );
+        assert_eq!(
+            a.downcast_get(0).unwrap().values().as_ptr(),
+            first.values()[2..].as_ptr()
+        );
+        assert_eq!(
+            a.downcast_get(1).unwrap().values().as_ptr(),
+            second.values().as_ptr()
+        );
+        assert_eq!(a.null_count(), 1);
+        assert_eq!(Vec::from(a), &[Some(3), Some(4), Some(1), None]);
+        assert_eq!(sliced.head(Some(1)).shape(), (1, 3));
+        assert_eq!(sliced.tail(Some(1)).column("b")?.str()?.get(0), Some("b"));
+
+        // Kernels respect the offsets of a sliced mask.
+        let mask = sliced.column("mask")?.bool()?;
+        let out = sliced.filter(mask)?;
+        assert_eq!(
+            Vec::from(out.column("b")?.str()?),
+            &[Some("c"), Some("d"), Some("a")]
+        );
+        Ok(())
+    }
+
     #[test]
     fn rechunk_false() {
         let df = create_frame();
@@ -3744,6 +3822,84 @@ mod test {
         assert!(out.equals(&expected));
     }
 
+    #[test]
+    fn test_unique_subset_keep() -> PolarsResult<()> {
+        let df = df! {
+            "k" => [Some(1.5), None, Some(1.5), None, Some(2.0)],
+            "v" => [1, 2, 3, 4, 5]
+        }?;
+        let subset = ["k".to_string()];
+
+        let out = df.unique_stable(Some(&subset[..]), UniqueKeepStrategy::First, None)?;
+        assert_eq!(
+            Vec::from(out.column("v")?.i32()?),
+            &[Some(1), Some(2), Some(5)]
+        );
+
+        let out = df.unique_stable(Some(&subset[..]), UniqueKeepStrategy::Last, None)?;
+        assert_eq!(
+            Vec::from(out.column("v")?.i32()?),
+            &[Some(3), Some(4), Some(5)]
+        );
+
+        let out = df.unique(Some(&subset[..]), UniqueKeepStrategy::First, None)?;
+        assert_eq!(out.height(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop_nulls_subset() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => [None, Some("x"), Some("y")]
+        }?;
+
+        let out = df.drop_nulls(Some(&["a".to_string()][..]))?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(3)]);
+        assert_eq!(Vec::from(out.column("b")?.str()?), &[None, Some("y")]);
+
+        let out = df.drop_nulls::<String>(None)?;
+        assert_eq!(out.height(), 1);
+
+        assert!(matches!(
+            df.drop_nulls(Some(&["c".to_string()][..])),
+            Err(PolarsError::ColumnNotFound(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "partition_by")]
+    fn test_partition_by_stable() -> PolarsResult<()> {
+        let df = df! {
+            "key" => ["b", "a", "b", "c", "a"],
+            "val" => [1, 2, 3, 4, 5]
+        }?;
+
+        let parts = df.partition_by_stable(["key"], true)?;
+        assert_eq!(parts.len(), 3);
+        let keys: Vec<_> = parts
+            .iter()
+            .map(|p| p.column("key").unwrap().str().unwrap().get(0).unwrap())
+            .collect();
+        assert_eq!(keys, ["b", "a", "c"]);
+        assert_eq!(
+            Vec::from(parts[0].column("val")?.i32()?),
+            &[Some(1), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(parts[1].column("val")?.i32()?),
+            &[Some(2), Some(5)]
+        );
+        assert_eq!(Vec::from(parts[2].column("val")?.i32()?), &[Some(4)]);
+
+        let parts = df.partition_by(["key"], false)?;
+        assert_eq!(parts.len(), 3);
+        assert!(parts.iter().all(|p| p.get_column_names_str() == ["val"]));
+        assert_eq!(parts.iter().map(|p| p.height()).sum::<usize>(), df.height());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-i8")]
     fn test_apply_result_schema() {
@@ -4,7 +4,7 @@ use arrow::bitmap::Bitmap;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::prelude::*;
-use crate::utils::try_get_supertype;
+use crate::utils::get_supertype;
 
 pub mod iceberg;
 
@@ -88,7 +88,12 @@ impl SchemaExt for Schema {
         for ((k, dt), (other_k, other_dt)) in self.iter_mut().zip(other.iter()) {
             polars_ensure!(k == other_k, ComputeError: "schema names differ: got {}, expected {}", k, other_k);
 
-            let st = try_get_supertype(dt, other_dt)?;
+            let st = get_supertype(dt, other_dt).ok_or_else(|| {
+                polars_err!(
+                    SchemaMismatch: "failed to determine supertype of {} and {} for column '{}'",
+                    dt, other_dt, k
+                )
+            })?;
             changed |= (&st != dt) || (&st != other_dt);
             *dt = st
         }
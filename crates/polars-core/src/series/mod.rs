@@ -376,6 +376,11 @@ impl Series {
         self._get_inner_mut().as_single_ptr()
     }
 
+    /// Cast [`Series`] to another [`DataType`], replacing values that cannot be represented
+    /// in the target type with null.
+    ///
+    /// E.g. `300i64` cast to `Int8` or `"abc"` cast to `Int32` become null. Floats cast to
+    /// integers are truncated towards zero. Use [`Series::strict_cast`] to raise instead.
     pub fn cast(&self, dtype: &DataType) -> PolarsResult<Self> {
         self.cast_with_options(dtype, CastOptions::NonStrict)
     }
@@ -634,6 +639,18 @@ impl Series {
         }
     }
 
+    /// Recursively explode a nested list Series until the inner dtype is no longer a list.
+    ///
+    /// Null lists at any nesting level produce a single null row. Empty lists do the same, unless
+    /// `skip_empty` is set, in which case they are dropped.
+    pub fn flatten(&self, skip_empty: bool) -> PolarsResult<Series> {
+        let mut out = self.explode(skip_empty)?;
+        while out.dtype().is_list() || out.dtype().is_array() {
+            out = out.explode(skip_empty)?;
+        }
+        Ok(out)
+    }
+
     /// Check if numeric value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
@@ -821,7 +838,10 @@ impl Series {
         }
     }
 
-    /// Cast throws an error if conversion had overflows
+    /// Cast throws an error if any non-null value cannot be represented in `dtype`.
+    ///
+    /// The error lists the offending values and the row of the first failure. Floats cast to
+    /// integers are still truncated towards zero; only values out of range fail.
     pub fn strict_cast(&self, dtype: &DataType) -> PolarsResult<Series> {
         self.cast_with_options(dtype, CastOptions::Strict)
     }
@@ -1146,6 +1166,37 @@ mod test {
         assert_eq!(list.dtype(), &DataType::List(Box::new(DataType::Date)));
     }
 
+    #[test]
+    fn flatten_nested_list() -> PolarsResult<()> {
+        let inner_a = Series::new(
+            "".into(),
+            &[
+                Some(Series::new("".into(), &[1i32, 2])),
+                None,
+                Some(Series::new_empty("".into(), &DataType::Int32)),
+            ],
+        );
+        let inner_b = Series::new("".into(), &[Some(Series::new("".into(), &[3i32]))]);
+        let s = Series::new("a".into(), &[Some(inner_a), None, Some(inner_b)]);
+        assert_eq!(
+            s.dtype(),
+            &DataType::List(Box::new(DataType::List(Box::new(DataType::Int32))))
+        );
+
+        let out = s.flatten(false)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(2), None, None, None, Some(3)]
+        );
+
+        let out = s.flatten(true)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(2), None, None, Some(3)]
+        );
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-struct")]
     fn new_series_from_empty_structs() {
@@ -7,7 +7,6 @@ use polars_compute::gather::sublist::list::array_to_unit_list;
 use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_utils::format_tuple;
 
-use crate::chunked_array::builder::get_list_builder;
 use crate::datatypes::{DataType, ListChunked};
 use crate::prelude::{IntoSeries, Series, *};
 
@@ -273,16 +272,22 @@ impl Series {
                     InvalidOperation: "cannot reshape len {} into shape {:?}", s_ref.len(), dimensions,
                 );
 
-                let mut builder =
-                    get_list_builder(s_ref.dtype(), s_ref.len(), rows as usize, s.name().clone());
-
-                let mut offset = 0u64;
-                for _ in 0..rows {
-                    let row = s_ref.slice(offset as i64, cols as usize);
-                    builder.append_series(&row).unwrap();
-                    offset += cols;
-                }
-                Ok(builder.finish().into_series())
+                // Every row has the same width, so we only need to create the offsets
+                // and can reuse the values as is.
+                let values = s_ref.rechunk().chunks()[0].clone();
+                let offsets = (0..=rows)
+                    .map(|row| (row * cols) as i64)
+                    .collect::<Vec<_>>();
+                // SAFETY:
+                // offsets are monotonically increasing and end at the length of the values.
+                let offsets: OffsetsBuffer<i64> = unsafe { Offsets::new_unchecked(offsets) }.into();
+                let dtype = ListArray::<i64>::default_datatype(values.dtype().clone());
+                let arr = ListArray::<i64>::new(dtype, offsets, values, None);
+
+                let mut ca = ListChunked::with_chunk(s.name().clone(), arr);
+                ca.set_inner_dtype(s_ref.dtype().clone());
+                ca.set_fast_explode();
+                Ok(ca.into_series())
             },
             _ => {
                 polars_bail!(InvalidOperation: "more than two dimensions not supported in reshaping to List.\n\nConsider reshaping to Array type.");
@@ -294,6 +299,7 @@ impl Series {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::chunked_array::builder::get_list_builder;
     use crate::prelude::*;
 
     #[test]
@@ -333,4 +339,34 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reshape_rows_and_back() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), [Some(1), Some(2), None, Some(4)]);
+        s.append(&Series::new("a".into(), [5, 6]))?;
+        let dims = |dims: &[i64]| {
+            dims.iter()
+                .map(|&v| ReshapeDimension::new(v))
+                .collect::<Vec<_>>()
+        };
+
+        let out = s.reshape_list(&dims(&[-1, 3]))?;
+        assert_eq!(out.len(), 2);
+        let ca = out.list()?;
+        assert_eq!(
+            Vec::from(ca.get_as_series(0).unwrap().i32()?),
+            &[Some(1), Some(2), None]
+        );
+        assert_eq!(
+            Vec::from(ca.get_as_series(1).unwrap().i32()?),
+            &[Some(4), Some(5), Some(6)]
+        );
+
+        // Flattening gives back the original values.
+        let flat = out.reshape_list(&dims(&[-1]))?;
+        assert!(flat.equals_missing(&s));
+
+        assert!(s.reshape_list(&dims(&[-1, 4])).is_err());
+        Ok(())
+    }
 }
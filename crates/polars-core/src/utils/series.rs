@@ -80,13 +80,14 @@ pub fn handle_casting_failures(input: &Series, output: &Series) -> PolarsResult<
 
     polars_bail!(
         InvalidOperation:
-        "conversion from `{}` to `{}` failed in column '{}' for {} out of {} values: {}{}",
+        "conversion from `{}` to `{}` failed in column '{}' for {} out of {} values: {} (first failure at row {}){}",
         input.dtype(),
         output.dtype(),
         output.name(),
         num_failures,
         input.len(),
         failures.fmt_list(),
+        idxs[0],
         additional_info,
     )
 }
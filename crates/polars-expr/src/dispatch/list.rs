@@ -77,12 +77,9 @@ pub(super) fn contains(args: &mut [Column], nulls_equal: bool) -> PolarsResult<C
     polars_ensure!(matches!(list.dtype(), DataType::List(_)),
         SchemaMismatch: "invalid series dtype: expected `List`, got `{}`", list.dtype(),
     );
-    let mut ca = polars_ops::prelude::is_in(
-        item.as_materialized_series(),
-        list.as_materialized_series(),
-        nulls_equal,
-    )?;
-    ca.rename(list.name().clone());
+    let ca = list
+        .list()?
+        .lst_contains(item.as_materialized_series(), nulls_equal)?;
     Ok(ca.into_column())
 }
 
@@ -164,12 +164,13 @@ impl Utf8Field {
     fn new(
         name: PlSmallStr,
         capacity: usize,
+        bytes_capacity: usize,
         quote_char: Option<u8>,
         encoding: CsvEncoding,
     ) -> Self {
         Self {
             name,
-            mutable: MutableBinaryViewArray::with_capacity(capacity),
+            mutable: MutableBinaryViewArray::with_capacities(capacity, bytes_capacity),
             scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
             encoding,
@@ -550,9 +551,13 @@ where
     }
 }
 
+/// Create the buffers for the projected columns.
+///
+/// `bytes_capacity` is a hint of the number of bytes every string column will hold.
 pub fn init_buffers(
     projection: &[usize],
     capacity: usize,
+    bytes_capacity: usize,
     schema: &Schema,
     quote_char: Option<u8>,
     encoding: CsvEncoding,
@@ -609,9 +614,13 @@ pub fn init_buffers(
                     scale,
                     decimal_comma,
                 )),
-                &DataType::String => {
-                    Buffer::Utf8(Utf8Field::new(name, capacity, quote_char, encoding))
-                },
+                &DataType::String => Buffer::Utf8(Utf8Field::new(
+                    name,
+                    capacity,
+                    bytes_capacity,
+                    quote_char,
+                    encoding,
+                )),
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(time_unit, time_zone) => Buffer::Datetime {
                     buf: DatetimeField::new(name, capacity),
@@ -757,7 +766,9 @@ impl Buffer {
                 .cast(&DataType::Date)
                 .unwrap(),
 
-            Buffer::Utf8(v) => {
+            Buffer::Utf8(mut v) => {
+                // The values capacity is only a hint, don't keep what wasn't used.
+                v.mutable.shrink_to_fit();
                 let arr = v.mutable.freeze();
                 StringChunked::with_chunk(v.name, unsafe { arr.to_utf8view_unchecked() })
                     .into_series()
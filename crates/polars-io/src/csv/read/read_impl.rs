@@ -536,6 +536,15 @@ pub fn read_chunk(
     starting_point_offset: Option<usize>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
+    // The projected string columns get an equal share of the bytes we parse as their values
+    // capacity. This overestimates, as the bytes include the other columns, delimiters and
+    // inlined values, so the reservation is capped and shrunk again when the buffers are finished.
+    let n_string_columns = projection
+        .iter()
+        .filter(|&&i| schema.get_at_index(i).unwrap().1 == &DataType::String)
+        .count();
+    let bytes_capacity =
+        stop_at_nbytes.saturating_sub(bytes_offset_thread) / n_string_columns.max(1);
     // There's an off-by-one error somewhere in the reading code, where it reads
     // one more item than the requested capacity. Given the batch sizes are
     // approximate (sometimes they're smaller), this isn't broken, but it does
@@ -544,6 +553,7 @@ pub fn read_chunk(
     let mut buffers = init_buffers(
         projection,
         capacity + 1,
+        bytes_capacity,
         schema,
         parse_options.quote_char,
         parse_options.encoding,
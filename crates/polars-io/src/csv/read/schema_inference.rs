@@ -322,6 +322,10 @@ fn infer_file_schema_inner(
         vec![PlHashSet::with_capacity(4); headers.len()];
     // keep track of columns with nulls
     let mut nulls: Vec<bool> = vec![false; headers.len()];
+    // keep track of the inferred date/datetime patterns, values in a column
+    // must share a single pattern to be parsed.
+    #[cfg(feature = "polars-time")]
+    let mut date_patterns: Vec<PlHashSet<Pattern>> = vec![PlHashSet::new(); headers.len()];
 
     let mut rows_count = 0;
     let mut fields = Vec::with_capacity(headers.len());
@@ -383,6 +387,8 @@ fn infer_file_schema_inner(
                     headers.push(column_name(i));
                     column_types.push(Default::default());
                     nulls.push(false);
+                    #[cfg(feature = "polars-time")]
+                    date_patterns.push(Default::default());
                 } else {
                     break;
                 }
@@ -451,6 +457,12 @@ fn infer_file_schema_inner(
                     },
                 };
                 if let Some(dtype) = dtype {
+                    #[cfg(feature = "polars-time")]
+                    if parse_options.try_parse_dates && dtype.is_temporal() {
+                        if let Some(pattern) = date_infer::infer_pattern_single(&s) {
+                            unsafe { date_patterns.get_unchecked_mut(i).insert(pattern) };
+                        }
+                    }
                     unsafe { column_types.get_unchecked_mut(i).insert(dtype) };
                 }
             }
@@ -479,6 +491,13 @@ fn infer_file_schema_inner(
 
         let possibilities = &column_types[i];
         let dtype = finish_infer_field_schema(possibilities);
+        // mixed date formats can't be parsed with a single pattern, default to String
+        #[cfg(feature = "polars-time")]
+        let dtype = if date_patterns[i].len() > 1 {
+            DataType::String
+        } else {
+            dtype
+        };
         fields.push(Field::new(field_name.clone(), dtype));
     }
     // if there is a single line after the header without an eol
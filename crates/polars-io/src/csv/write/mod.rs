@@ -22,4 +22,4 @@ mod write_impl;
 mod writer;
 
 pub use options::{CsvWriterOptions, QuoteStyle, SerializeOptions};
-pub use writer::{BatchedWriter, CsvWriter};
+pub use writer::{BatchedWriter, CsvCompression, CsvWriter};
@@ -10,6 +10,91 @@ use super::write_impl::{write, write_bom, write_header};
 use super::{QuoteStyle, SerializeOptions};
 use crate::shared::SerWriter;
 
+/// Compression of the output of a [`CsvWriter`].
+///
+/// Gzip and zstd require the `decompress` feature, the reader detects both formats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CsvCompression {
+    #[default]
+    Uncompressed,
+    #[cfg(feature = "decompress")]
+    Gzip,
+    #[cfg(feature = "decompress")]
+    Zstd,
+}
+
+impl CsvCompression {
+    /// Wrap `writer` in a streaming encoder for this compression.
+    fn encoder<W: Write>(self, writer: W) -> PolarsResult<CsvEncoder<W>> {
+        Ok(match self {
+            CsvCompression::Uncompressed => CsvEncoder::Uncompressed(writer),
+            #[cfg(feature = "decompress")]
+            CsvCompression::Gzip => CsvEncoder::Gzip(flate2::write::GzEncoder::new(
+                writer,
+                flate2::Compression::default(),
+            )),
+            #[cfg(feature = "decompress")]
+            CsvCompression::Zstd => CsvEncoder::Zstd(zstd::stream::write::Encoder::new(writer, 0)?),
+        })
+    }
+}
+
+/// Compresses everything written to it into a single gzip member or zstd frame.
+enum CsvEncoder<W: Write> {
+    Uncompressed(W),
+    #[cfg(feature = "decompress")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "decompress")]
+    Zstd(zstd::stream::write::Encoder<'static, W>),
+}
+
+impl<W: Write> CsvEncoder<W> {
+    /// The writer the compressed output goes to.
+    fn get_mut(&mut self) -> &mut W {
+        match self {
+            CsvEncoder::Uncompressed(writer) => writer,
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Gzip(encoder) => encoder.get_mut(),
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Zstd(encoder) => encoder.get_mut(),
+        }
+    }
+
+    /// Write the remaining compressed output and end the stream.
+    fn try_finish(&mut self) -> PolarsResult<()> {
+        match self {
+            CsvEncoder::Uncompressed(_) => {},
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Gzip(encoder) => encoder.try_finish()?,
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Zstd(encoder) => encoder.do_finish()?,
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for CsvEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            CsvEncoder::Uncompressed(writer) => writer.write(buf),
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            CsvEncoder::Uncompressed(writer) => writer.flush(),
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "decompress")]
+            CsvEncoder::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
+
 /// Write a DataFrame to csv.
 ///
 /// Don't use a `Buffered` writer, the `CsvWriter` internally already buffers writes.
@@ -22,6 +107,7 @@ pub struct CsvWriter<W: Write> {
     bom: bool,
     batch_size: NonZeroUsize,
     n_threads: usize,
+    compression: CsvCompression,
 }
 
 impl<W> SerWriter<W> for CsvWriter<W>
@@ -42,12 +128,15 @@ where
             bom: false,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             n_threads: POOL.current_num_threads(),
+            compression: CsvCompression::default(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut buffer = self.compression.encoder(&mut self.buffer)?;
+
         if self.bom {
-            write_bom(&mut self.buffer)?;
+            write_bom(&mut buffer)?;
         }
         let names = df
             .get_column_names()
@@ -55,15 +144,17 @@ where
             .map(|x| x.as_str())
             .collect::<Vec<_>>();
         if self.header {
-            write_header(&mut self.buffer, names.as_slice(), &self.options)?;
+            write_header(&mut buffer, names.as_slice(), &self.options)?;
         }
         write(
-            &mut self.buffer,
+            &mut buffer,
             df,
             self.batch_size.into(),
             &self.options,
             self.n_threads,
-        )
+        )?;
+
+        buffer.try_finish()
     }
 }
 
@@ -171,14 +262,29 @@ where
         self
     }
 
+    /// Set the compression of the output.
+    ///
+    /// A [`BatchedWriter`] compresses all batches into a single gzip member or zstd frame, which
+    /// is only complete once [`BatchedWriter::finish`] is called.
+    pub fn with_compression(mut self, compression: CsvCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let expects_bom = self.bom;
         let expects_header = self.header;
+        let encoder = if self.compression == CsvCompression::Uncompressed {
+            None
+        } else {
+            Some(self.compression.encoder(Vec::new())?)
+        };
         Ok(BatchedWriter {
             writer: self,
             has_written_bom: !expects_bom,
             has_written_header: !expects_header,
             schema: schema.clone(),
+            encoder,
         })
     }
 }
@@ -188,6 +294,10 @@ pub struct BatchedWriter<W: Write> {
     has_written_bom: bool,
     has_written_header: bool,
     schema: Schema,
+    /// Compresses the batches into a buffer that is moved to the writer after every batch. The
+    /// encoder doesn't own the writer, so a borrowed writer is released as soon as the
+    /// [`BatchedWriter`] is no longer used.
+    encoder: Option<CsvEncoder<Vec<u8>>>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -196,9 +306,14 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut buffer: &mut dyn Write = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => &mut self.writer.buffer,
+        };
+
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut buffer)?;
         }
 
         if !self.has_written_header {
@@ -208,28 +323,29 @@ impl<W: Write> BatchedWriter<W> {
                 .into_iter()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(
-                &mut self.writer.buffer,
-                names.as_slice(),
-                &self.writer.options,
-            )?;
+            write_header(&mut buffer, names.as_slice(), &self.writer.options)?;
         }
 
         write(
-            &mut self.writer.buffer,
+            &mut buffer,
             df,
             self.writer.batch_size.into(),
             &self.writer.options,
             self.writer.n_threads,
         )?;
-        Ok(())
+        self.flush_compressed()
     }
 
     /// Writes the header of the csv file if not done already. Returns the total size of the file.
     pub fn finish(&mut self) -> PolarsResult<()> {
+        let mut buffer: &mut dyn Write = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => &mut self.writer.buffer,
+        };
+
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut buffer)?;
         }
 
         if !self.has_written_header {
@@ -239,9 +355,22 @@ impl<W: Write> BatchedWriter<W> {
                 .iter_names()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(&mut self.writer.buffer, &names, &self.writer.options)?;
+            write_header(&mut buffer, &names, &self.writer.options)?;
         };
 
+        if let Some(encoder) = &mut self.encoder {
+            encoder.try_finish()?;
+        }
+        self.flush_compressed()
+    }
+
+    /// Move the compressed output produced so far into the writer.
+    fn flush_compressed(&mut self) -> PolarsResult<()> {
+        if let Some(encoder) = &mut self.encoder {
+            let compressed = encoder.get_mut();
+            self.writer.buffer.write_all(compressed)?;
+            compressed.clear();
+        }
         Ok(())
     }
 }
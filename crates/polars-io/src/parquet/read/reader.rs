@@ -48,6 +48,11 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Stop reading after `num_rows` rows. Row groups after the last requested row are not read.
+    pub fn with_n_rows(self, num_rows: Option<usize>) -> Self {
+        self.with_slice(num_rows.map(|n| (0, n)))
+    }
+
     /// Columns to select/ project
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
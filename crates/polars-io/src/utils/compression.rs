@@ -34,6 +34,9 @@ impl SupportedCompression {
 
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
 /// An `out` vec must be given for ownership of the decompressed data.
+///
+/// The data is decompressed into memory in full, which errors if the decompressed data does not
+/// fit in memory.
 #[allow(clippy::ptr_arg)]
 pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> PolarsResult<&'a [u8]> {
     assert!(out.is_empty());
@@ -42,17 +45,13 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         feature_gated!("decompress", {
             match algo {
                 SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
+                    decompress_to_end(flate2::read::MultiGzDecoder::new(bytes), out)?;
                 },
                 SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
+                    decompress_to_end(flate2::read::ZlibDecoder::new(bytes), out)?;
                 },
                 SupportedCompression::ZSTD => {
-                    zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
+                    decompress_to_end(zstd::Decoder::with_buffer(bytes)?, out)?;
                 },
             }
 
@@ -62,3 +61,65 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Read the decompressed data into `out`, growing it with a fallible allocation so that running
+/// out of memory is an error instead of an abort.
+#[cfg(feature = "decompress")]
+fn decompress_to_end<R: Read>(mut reader: R, out: &mut Vec<u8>) -> PolarsResult<()> {
+    const CHUNK_SIZE: usize = 1 << 16;
+
+    loop {
+        if out.try_reserve(CHUNK_SIZE).is_err() {
+            polars_bail!(
+                ComputeError: "could not allocate memory to decompress the file after {} bytes: \
+                compressed files are decompressed into memory in full before they are read, \
+                consider decompressing the file on disk first",
+                out.len()
+            );
+        }
+        let n_read = (&mut reader)
+            .take(CHUNK_SIZE as u64)
+            .read_to_end(out)
+            .map_err(to_compute_err)?;
+        if n_read == 0 {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "decompress"))]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn compressed(bytes: &[u8]) -> Vec<(&'static str, Vec<u8>)> {
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(bytes).unwrap();
+        let mut zlib = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        zlib.write_all(bytes).unwrap();
+        vec![
+            ("gzip", gzip.finish().unwrap()),
+            ("zlib", zlib.finish().unwrap()),
+            ("zstd", zstd::encode_all(bytes, 0).unwrap()),
+        ]
+    }
+
+    #[test]
+    fn test_maybe_decompress_bytes() {
+        let data = b"a,b\n1,2\n3,4\n";
+
+        let mut out = vec![];
+        assert_eq!(maybe_decompress_bytes(data, &mut out).unwrap(), data);
+
+        for (name, bytes) in compressed(data) {
+            assert!(SupportedCompression::check(&bytes).is_some(), "{name}");
+            let mut out = vec![];
+            assert_eq!(
+                maybe_decompress_bytes(&bytes, &mut out).unwrap(),
+                data,
+                "{name}"
+            );
+        }
+    }
+}
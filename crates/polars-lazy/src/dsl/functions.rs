@@ -132,4 +132,75 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_concat_relaxed() -> PolarsResult<()> {
+        let a = df![
+            "x" => [1i32, 2],
+            "y" => ["a", "b"]
+        ]?;
+        let b = df![
+            "x" => [3i64, 4],
+            "y" => ["c", "d"]
+        ]?;
+
+        // Without relaxing, the differing dtypes of `x` are an error.
+        assert!(
+            concat(&[a.clone().lazy(), b.clone().lazy()], UnionArgs::default())?
+                .collect()
+                .is_err()
+        );
+
+        let q = concat(
+            &[a.lazy(), b.lazy()],
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )?
+        .filter(col("x").gt(lit(1)))
+        .select([col("x")]);
+
+        // The filter and projection are pushed into every input of the union.
+        let mut expr_arena = Arena::with_capacity(16);
+        let mut lp_arena = Arena::with_capacity(16);
+        let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        let IR::Union { inputs, .. } = lp_arena.get(root) else {
+            panic!("expected a union at the root");
+        };
+        for input in inputs {
+            assert!(
+                lp_arena
+                    .iter(*input)
+                    .any(|(_, lp)| matches!(lp, IR::Filter { .. }))
+            );
+        }
+
+        let out = q.collect()?;
+        assert_eq!(out.column("x")?.dtype(), &DataType::Int64);
+        assert_eq!(
+            Vec::from(out.column("x")?.i64()?),
+            &[Some(2), Some(3), Some(4)]
+        );
+
+        // Columns without a common supertype are named in the error.
+        let c = df![
+            "x" => [1i32, 2]
+        ]?;
+        let d = DataFrame::new(vec![
+            BinaryChunked::from_slice("x".into(), &[b"a".as_slice(), b"b"]).into_column(),
+        ])?;
+        let err = concat(
+            &[c.lazy(), d.lazy()],
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )?
+        .collect()
+        .unwrap_err();
+        assert!(err.to_string().contains("i32 and binary for column 'x'"));
+
+        Ok(())
+    }
 }
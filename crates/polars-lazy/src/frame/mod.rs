@@ -35,6 +35,7 @@ use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
 use crate::frame::cached_arenas::CachedArena;
@@ -828,6 +829,62 @@ impl LazyFrame {
         self.collect_with_engine(Engine::InMemory)
     }
 
+    /// Execute the query, but read at most `n_rows` rows from every source node.
+    ///
+    /// This is meant to cheaply check the schema and logic of a query on a large
+    /// source. It is not the same as `collect` followed by a `head`: filters, joins and
+    /// aggregations run on the truncated sources, so their results are partial and the
+    /// output may have fewer (or, after a join, more) than `n_rows` rows.
+    pub fn fetch(self, n_rows: usize) -> PolarsResult<DataFrame> {
+        self._collect_post_opt(|lp_top, lp_arena, _, _| {
+            let nodes = lp_arena
+                .iter(lp_top)
+                .map(|(node, _)| node)
+                .collect::<Vec<_>>();
+            for node in nodes {
+                match lp_arena.get_mut(node) {
+                    IR::Scan {
+                        predicate,
+                        unified_scan_args,
+                        ..
+                    } => {
+                        // A scan applies its predicate before its slice, but the rows must be
+                        // fetched first. Move a pushed down predicate into a filter on top.
+                        let predicate = predicate.take();
+                        unified_scan_args.pre_slice =
+                            Some(match unified_scan_args.pre_slice.take() {
+                                None => Slice::Positive {
+                                    offset: 0,
+                                    len: n_rows,
+                                },
+                                Some(Slice::Positive { offset, len }) => Slice::Positive {
+                                    offset,
+                                    len: len.min(n_rows),
+                                },
+                                Some(Slice::Negative {
+                                    offset_from_end,
+                                    len,
+                                }) => Slice::Negative {
+                                    offset_from_end,
+                                    len: len.min(n_rows),
+                                },
+                            });
+                        if let Some(predicate) = predicate {
+                            let scan = lp_arena.take(node);
+                            let input = lp_arena.add(scan);
+                            lp_arena.replace(node, IR::Filter { input, predicate });
+                        }
+                    },
+                    IR::DataFrameScan { df, .. } if df.height() > n_rows => {
+                        *df = Arc::new(df.head(Some(n_rows)));
+                    },
+                    _ => {},
+                }
+            }
+            Ok(())
+        })
+    }
+
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     // This version does profiling of the node execution.
     pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
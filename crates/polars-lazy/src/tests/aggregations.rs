@@ -568,3 +568,337 @@ fn test_anonymous_function_returns_scalar_all_null_20679() {
 
     assert_eq!(grouped_df.get_columns()[1].dtype(), &DataType::Null);
 }
+
+#[test]
+fn test_agg_same_column_output_names() -> PolarsResult<()> {
+    let df = fruits_cars();
+
+    // Aggregations of the same column without an alias get the aggregation as suffix.
+    let q = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("cars")])
+        .agg([
+            col("A").sum(),
+            col("A").mean(),
+            col("A").max().alias("A_top"),
+            col("B").min(),
+        ])
+        .select([
+            col("cars"),
+            col("A_sum"),
+            col("A_mean"),
+            col("A_top"),
+            col("B"),
+        ]);
+
+    // The suffixed outputs resolve when the plan is built.
+    let schema = q.clone().collect_schema()?;
+    assert_eq!(
+        schema.iter_names().map(|n| n.as_str()).collect::<Vec<_>>(),
+        &["cars", "A_sum", "A_mean", "A_top", "B"]
+    );
+
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("A_sum")?.i32()?), &[Some(13), Some(2)]);
+    assert_eq!(Vec::from(out.column("A_top")?.i32()?), &[Some(5), Some(2)]);
+
+    // Names that are still duplicated after suffixing are an error.
+    let err = df
+        .lazy()
+        .group_by([col("cars")])
+        .agg([col("A").sum(), col("A").sum()])
+        .collect_schema()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::Duplicate(_)));
+    Ok(())
+}
+
+#[test]
+fn test_agg_same_column_suffix_per_aggregation() -> PolarsResult<()> {
+    let aggs = [
+        (col("A").min(), "A_min"),
+        (col("A").max(), "A_max"),
+        (col("A").median(), "A_median"),
+        (col("A").n_unique(), "A_n_unique"),
+        (col("A").first(), "A_first"),
+        (col("A").last(), "A_last"),
+        (col("A").mean(), "A_mean"),
+        (col("A").implode(), "A_implode"),
+        (
+            col("A").quantile(lit(0.25), QuantileMethod::Linear),
+            "A_quantile_0.25",
+        ),
+        (
+            col("A").quantile(lit(0.75), QuantileMethod::Linear),
+            "A_quantile_0.75",
+        ),
+        (col("A").sum(), "A_sum"),
+        (col("A").count(), "A_count"),
+        (col("A").len(), "A_len"),
+        (col("A").std(1), "A_std"),
+        (col("A").var(1), "A_var"),
+        (col("A").agg_groups(), "A_agg_groups"),
+    ];
+    let (aggs, expected): (Vec<_>, Vec<_>) = aggs.into_iter().unzip();
+
+    let out = fruits_cars()
+        .lazy()
+        .group_by_stable([col("cars")])
+        .agg(aggs)
+        .collect()?;
+    assert_eq!(
+        out.get_column_names()
+            .into_iter()
+            .map(|n| n.as_str())
+            .collect::<Vec<_>>(),
+        [&["cars"][..], &expected].concat()
+    );
+    assert_eq!(
+        Vec::from(out.column("A_quantile_0.25")?.f64()?),
+        &[Some(2.5), Some(2.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("A_quantile_0.75")?.f64()?),
+        &[Some(4.25), Some(2.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_agg_list_explode_round_trip() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["b", "a", "b", "a", "b"],
+        "x" => [Some(1), None, Some(3), Some(4), None]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by([col("g")])
+        .agg([col("x")])
+        .sort(["g"], Default::default())
+        .collect()?;
+    assert_eq!(
+        out.column("x")?.dtype(),
+        &DataType::List(Box::new(DataType::Int32))
+    );
+    // Within-group order and nulls are kept.
+    let lists = out.column("x")?.list()?;
+    assert_eq!(
+        Vec::from(lists.get_as_series(1).unwrap().i32()?),
+        &[Some(1), Some(3), None]
+    );
+
+    let exploded = out.lazy().explode(by_name(["x"], true)).collect()?;
+    let expected = df.sort(
+        ["g"],
+        SortMultipleOptions::default().with_maintain_order(true),
+    )?;
+    assert!(exploded.equals_missing(&expected));
+
+    // Use of deprecated `agg_list()` for testing purposes
+    #[allow(deprecated)]
+    let eager = df.group_by_stable(["g"])?.select(["x"]).agg_list()?;
+    assert_eq!(
+        Vec::from(&eager.column("x_agg_list")?.list()?.lst_lengths()),
+        &[Some(3), Some(2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_group_by_stable_first_occurrence_order() -> PolarsResult<()> {
+    let df = df![
+        "k" => ["c", "a", "b", "a", "c", "d", "b"],
+        "k2" => [1, 1, 2, 1, 2, 1, 2],
+        "v" => [1, 2, 3, 4, 5, 6, 7]
+    ]?;
+
+    // First-occurrence order differs from the sorted key order and is stable between runs.
+    for _ in 0..5 {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_stable([col("k")])
+            .agg([col("v").sum()])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("k")?.str()?),
+            &[Some("c"), Some("a"), Some("b"), Some("d")]
+        );
+        assert_eq!(
+            Vec::from(out.column("v")?.i32()?),
+            &[Some(6), Some(6), Some(10), Some(6)]
+        );
+
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_stable([col("k"), col("k2")])
+            .agg([col("v").first()])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("v")?.i32()?),
+            &[Some(1), Some(2), Some(3), Some(5), Some(6)]
+        );
+    }
+
+    let out = df.group_by_stable(["k"])?.select(["v"]).count()?;
+    assert_eq!(
+        Vec::from(out.column("k")?.str()?),
+        &[Some("c"), Some("a"), Some("b"), Some("d")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_n_unique_with_and_without_nulls() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "c"],
+        "user_id" => [Some(1), Some(1), None, Some(2), Some(3), None]
+    ]?;
+
+    // Null counts as a distinct value, dropping nulls first gives `COUNT(DISTINCT)`.
+    assert_eq!(df.column("user_id")?.n_unique()?, 4);
+    assert_eq!(
+        df.column("user_id")?
+            .as_materialized_series()
+            .drop_nulls()
+            .n_unique()?,
+        3
+    );
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("user_id").n_unique().alias("with_nulls"),
+            col("user_id").drop_nulls().n_unique().alias("distinct"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("with_nulls")?.idx()?),
+        &[Some(2), Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("distinct")?.idx()?),
+        &[Some(1), Some(2), Some(0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "mode", feature = "moment"))]
+fn test_mode_product_and_moments_agg() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "a", "a", "a", "b", "b", "b"],
+        "v" => [Some(1), Some(2), Some(3), Some(4), Some(5), Some(23), Some(2), None, Some(4)],
+        "m" => [1, 2, 2, 3, 3, 1, 7, 7, 8]
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("m").mode().sort(Default::default()).alias("mode"),
+            col("v").product().alias("product"),
+            col("v").skew(true).alias("skew"),
+            col("v").kurtosis(true, true).alias("kurtosis"),
+        ])
+        .collect()?;
+
+    // All tied values are returned as the mode.
+    let mode = out.column("mode")?.list()?;
+    assert_eq!(
+        Vec::from(mode.get_as_series(0).unwrap().i32()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(Vec::from(mode.get_as_series(1).unwrap().i32()?), &[Some(7)]);
+
+    // Nulls are excluded from the product and the moments.
+    assert_eq!(
+        Vec::from(out.column("product")?.i64()?),
+        &[Some(2760), Some(8)]
+    );
+    let skew = out.column("skew")?.f64()?;
+    assert!((skew.get(0).unwrap() - 1.6727687946848508).abs() < 1e-9);
+    let kurtosis = out.column("kurtosis")?.f64()?;
+    assert!((kurtosis.get(0).unwrap() - 0.9945668771797536).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn test_low_cardinality_group_by_partitioned_matches_generic() -> PolarsResult<()> {
+    let n = 10_000;
+    let k = (0..n)
+        .map(|i| ["NL", "DE", "FR"][i % 3])
+        .collect::<Vec<_>>();
+    let v = (0..n as i64).collect::<Vec<_>>();
+    let df = df![
+        "k" => k,
+        "v" => v,
+    ]?;
+
+    let aggs = [
+        col("v").sum().alias("sum"),
+        col("v").min().alias("min"),
+        col("v").max().alias("max"),
+        col("v").count().alias("count"),
+        col("v").mean().alias("mean"),
+    ];
+    // A plain column key with pre-aggregatable aggregations takes the partitioned path.
+    let sort = SortMultipleOptions::default();
+    let partitioned = df
+        .clone()
+        .lazy()
+        .group_by([col("k")])
+        .agg(aggs)
+        .sort(["k"], sort.clone())
+        .collect()?;
+
+    // The eager aggregations always take the generic path.
+    let gb = df.group_by(["k"])?.select(["v"]);
+    // Use of deprecated eager aggregations for testing purposes
+    #[allow(deprecated)]
+    let generic = [gb.sum()?, gb.min()?, gb.max()?, gb.count()?, gb.mean()?];
+    for (name, generic) in ["sum", "min", "max", "count", "mean"]
+        .into_iter()
+        .zip(generic)
+    {
+        let generic = generic.sort(["k"], sort.clone())?;
+        assert!(partitioned.column(name)?.equals(&generic.get_columns()[1]));
+    }
+
+    assert_eq!(
+        Vec::from(partitioned.column("k")?.str()?),
+        &[Some("DE"), Some("FR"), Some("NL")]
+    );
+    let count = n as i64 / 3;
+    let sum = |offset: i64| {
+        (0..count + (offset == 0) as i64)
+            .map(|j| 3 * j + offset)
+            .sum::<i64>()
+    };
+    assert_eq!(
+        Vec::from(partitioned.column("sum")?.i64()?),
+        &[Some(sum(1)), Some(sum(2)), Some(sum(0))]
+    );
+    assert_eq!(
+        Vec::from(partitioned.column("min")?.i64()?),
+        &[Some(1), Some(2), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(partitioned.column("max")?.i64()?),
+        &[Some(9997), Some(9998), Some(9999)]
+    );
+    assert_eq!(
+        Vec::from(partitioned.column("count")?.idx()?),
+        &[Some(3333), Some(3333), Some(3334)]
+    );
+    assert_eq!(
+        Vec::from(partitioned.column("mean")?.f64()?),
+        &[Some(4999.0), Some(5000.0), Some(4999.5)]
+    );
+    Ok(())
+}
@@ -345,3 +345,45 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cached_subplan_executes_once() -> PolarsResult<()> {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let udf_counter = counter.clone();
+    let base = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6]
+    ]?
+    .lazy()
+    .with_column(col("b").map(
+        move |c| {
+            udf_counter.fetch_add(1, Ordering::Relaxed);
+            Ok(c)
+        },
+        |_, f| Ok(f.clone()),
+    ));
+
+    // An explicit cache is shared by both sides of the join.
+    let cached = base.clone().cache();
+    let out = cached
+        .clone()
+        .inner_join(cached, col("a"), col("a"))
+        .with_comm_subplan_elim(false)
+        .collect()?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(counter.swap(0, Ordering::Relaxed), 1);
+
+    // Common subplan elimination inserts the cache itself.
+    let q = base
+        .clone()
+        .inner_join(base, col("a"), col("a"))
+        .with_comm_subplan_elim(true);
+    assert_eq!(count_caches(q.clone()), 2);
+    let out = q.collect()?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+    Ok(())
+}
@@ -435,6 +435,34 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_globbing_projection_slice_and_schema_mismatch() -> PolarsResult<()> {
+    init_files();
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let glob = "../../examples/datasets/foods*.parquet";
+    let q = LazyFrame::scan_parquet(PlPath::new(glob), Default::default())?
+        .select([col("calories")])
+        .limit(3);
+    let df = q.collect()?;
+    assert_eq!(df.get_column_names_str(), &["calories"]);
+    assert_eq!(df.height(), 3);
+
+    // `null_nutriscore` has columns that are not in the schema of the first file.
+    let err = LazyFrame::scan_parquet_files(
+        FromIterator::from_iter([
+            PlPath::new(FOODS_PARQUET),
+            PlPath::new(NUTRI_SCORE_NULL_COLUMN_PARQUET),
+        ]),
+        Default::default(),
+    )?
+    .collect()
+    .unwrap_err();
+    assert!(err.to_string().contains("nutri_score"));
+
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     lp_arena.iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
@@ -44,6 +44,52 @@ fn test_duration() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "temporal", feature = "dtype-duration"))]
+fn test_filter_within_duration() -> PolarsResult<()> {
+    let day = MILLISECONDS_IN_DAY;
+    let df = DataFrame::new(vec![
+        Int32Chunked::new("signup".into(), &[0, 0, 10])
+            .into_date()
+            .into_column(),
+        Int32Chunked::new("event".into(), &[5, 31, 40])
+            .into_date()
+            .into_column(),
+        Int64Chunked::new("seen".into(), &[day, 45 * day, 20 * day])
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_column(),
+    ])?;
+    let thirty_days = duration(DurationArgs::new().with_days(lit(30)));
+
+    let out = df
+        .lazy()
+        .with_columns([
+            (col("event") - col("signup")).alias("elapsed"),
+            (col("seen") + thirty_days.clone()).alias("seen_deadline"),
+        ])
+        .filter((col("event") - col("signup")).lt_eq(thirty_days))
+        .collect()?;
+
+    assert!(matches!(
+        out.column("elapsed")?.dtype(),
+        DataType::Duration(_)
+    ));
+    // The result takes the finer of the two time units.
+    assert_eq!(
+        out.column("seen_deadline")?.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    assert_eq!(
+        Vec::from(out.column("event")?.to_physical_repr().i32()?),
+        &[Some(5), Some(40)]
+    );
+    assert_eq!(
+        out.column("seen_deadline")?.get(0)?,
+        AnyValue::Datetime(31 * MICROSECONDS_IN_DAY, TimeUnit::Microseconds, None)
+    );
+    Ok(())
+}
+
 fn print_plans(lf: &LazyFrame) {
     println!("LOGICAL PLAN\n\n{}\n", lf.describe_plan().unwrap());
     println!(
@@ -151,3 +197,24 @@ fn test_lazy_logical_plan_join() {
         let _df = lf.collect().unwrap();
     }
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_describe_plans() -> PolarsResult<()> {
+    let lf = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .select([col("category"), col("calories")]);
+
+    let plan = lf.describe_plan()?;
+    assert!(plan.contains("FILTER"));
+    assert!(plan.lines().count() > 1);
+
+    // The filter and projection end up on the scan node.
+    let optimized = lf.describe_optimized_plan()?;
+    assert!(!optimized.contains("FILTER"));
+    assert!(optimized.contains("SELECTION"));
+    assert!(optimized.contains("PROJECT 2/4 COLUMNS"));
+
+    assert!(lf.to_dot(true)?.starts_with("digraph polars_query {"));
+    Ok(())
+}
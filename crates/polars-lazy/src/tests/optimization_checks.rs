@@ -242,6 +242,56 @@ pub fn test_slice_pushdown_sort() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "csv")]
+pub fn test_slice_not_pushed_below_filter() -> PolarsResult<()> {
+    let q = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .with_column((col("fats_g") * lit(2)).alias("fats_x2"))
+        .limit(3);
+
+    // The slice must stay above the filter, so the scan reads all rows.
+    assert!(!slice_at_scan(q.clone()));
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(
+        lp_arena
+            .iter(lp)
+            .any(|(_, lp)| matches!(lp, IR::Slice { len: 3, .. }))
+    );
+
+    let out = q.collect()?;
+    assert_eq!(out.height(), 3);
+    assert!(
+        out.column("calories")?
+            .i64()?
+            .into_no_null_iter()
+            .all(|v| v > 100)
+    );
+
+    // Top-k on an in-memory frame becomes a sort with a slice.
+    let df = df!["a" => [5, 3, 1, 4, 2]]?;
+    let q = df
+        .lazy()
+        .sort(["a"], SortMultipleOptions::default())
+        .limit(2);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(matches!(
+        lp_arena.get(lp),
+        IR::Sort {
+            slice: Some((0, 2)),
+            ..
+        }
+    ));
+    assert_eq!(
+        Vec::from(q.collect()?.column("a")?.i32()?),
+        &[Some(1), Some(2)]
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-i16")]
 pub fn test_predicate_block_cast() -> PolarsResult<()> {
@@ -477,6 +527,127 @@ fn test_slice_at_scan_group_by() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "csv")]
+fn test_projection_predicate_and_slice_at_csv_scan() -> PolarsResult<()> {
+    let q = scan_foods_csv()
+        .select([col("category"), col("calories")])
+        .filter(col("calories").gt(lit(100)));
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(lp_arena.iter(lp).any(|(_, lp)| {
+        match lp {
+            IR::Scan {
+                unified_scan_args, ..
+            } => unified_scan_args
+                .projection
+                .as_deref()
+                .is_some_and(|p| p.len() == 2),
+            _ => false,
+        }
+    }));
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    assert_eq!(out.get_column_names_str(), &["category", "calories"]);
+    assert!(
+        out.column("calories")?
+            .i64()?
+            .into_no_null_iter()
+            .all(|v| v > 100)
+    );
+
+    // A limit without a preceding filter becomes a row limit on the reader.
+    let q = scan_foods_csv().select([col("fats_g")]).limit(3);
+    assert!(slice_at_scan(q.clone()));
+    assert_eq!(q.collect()?.shape(), (3, 1));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_optimization_toggles() -> PolarsResult<()> {
+    let q = scan_foods_csv().filter(col("calories").gt(lit(100)));
+    assert!(predicate_at_scan(q.clone()));
+
+    for q in [
+        q.clone().with_predicate_pushdown(false),
+        q.clone().without_optimizations(),
+    ] {
+        assert!(!predicate_at_scan(q.clone()));
+        let plan = q.describe_optimized_plan()?;
+        assert!(plan.starts_with("FILTER"));
+        assert!(!plan.contains("SELECTION"));
+    }
+
+    let q = scan_foods_csv().select([col("fats_g")]);
+    let plan = q
+        .clone()
+        .with_projection_pushdown(false)
+        .describe_optimized_plan()?;
+    assert!(plan.contains("PROJECT */4 COLUMNS"));
+    let plan = q.describe_optimized_plan()?;
+    assert!(plan.contains("PROJECT 1/4 COLUMNS"));
+    Ok(())
+}
+
+#[test]
+fn test_simplify_expr() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "c" => [true, false, true]
+    ]?;
+    let q = df
+        .lazy()
+        .filter(lit(true))
+        .filter(lit(2).gt(lit(1)))
+        .select([
+            (lit(2) + lit(3)).alias("five"),
+            col("a")
+                .cast(DataType::Int64)
+                .cast(DataType::Int64)
+                .alias("b"),
+            col("c").not().not().alias("c2"),
+            col("c").and(lit(true)).alias("c3"),
+        ]);
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(
+        !lp_arena
+            .iter(lp)
+            .any(|(_, lp)| matches!(lp, IR::Filter { .. }))
+    );
+    let IR::Select { expr, .. } = lp_arena.get(lp) else {
+        panic!("expected a select at the root")
+    };
+    let count = |e: &ExprIR, f: fn(&AExpr) -> bool| {
+        expr_arena.iter(e.node()).filter(|(_, ae)| f(ae)).count()
+    };
+    assert!(matches!(expr_arena.get(expr[0].node()), AExpr::Literal(_)));
+    assert_eq!(count(&expr[1], |ae| matches!(ae, AExpr::Cast { .. })), 1);
+    assert_eq!(
+        count(&expr[2], |ae| matches!(
+            ae,
+            AExpr::Function {
+                function: IRFunctionExpr::Boolean(IRBooleanFunction::Not),
+                ..
+            }
+        )),
+        0
+    );
+    assert_eq!(
+        count(&expr[3], |ae| matches!(ae, AExpr::BinaryExpr { .. })),
+        0
+    );
+
+    // Simplification does not change the result.
+    let expected = q.clone().with_simplify_expr(false).collect()?;
+    assert!(q.collect()?.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_flatten_unions() -> PolarsResult<()> {
     let (mut expr_arena, mut lp_arena) = get_arenas();
@@ -314,3 +314,86 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_join_on_cast_key_with_different_names() -> PolarsResult<()> {
+    let left = df! {
+        "a" => [1i32, 2, 3, 4],
+        "lv" => [10, 20, 30, 40],
+    }?;
+    let right = df! {
+        "b" => [2i64, 3, 4, 5],
+        "rv" => ["x", "y", "z", "w"],
+    }?;
+
+    let q = left
+        .lazy()
+        .join(
+            right.lazy(),
+            [col("a").cast(DataType::Int64)],
+            [col("b")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .filter(col("lv").gt(lit(20)))
+        .filter(col("rv").neq(lit("z")))
+        .sort(["lv"], Default::default());
+
+    // Both single-side predicates are pushed below the join onto their own input.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    let filters = lp_arena
+        .iter(lp)
+        .filter_map(|(_, lp)| match lp {
+            IR::Filter { input, .. } => Some(*input),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(filters.len(), 2);
+    assert!(
+        filters
+            .iter()
+            .all(|input| matches!(lp_arena.get(*input), IR::DataFrameScan { .. }))
+    );
+
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("lv")?.i32()?), &[Some(30)]);
+    assert_eq!(Vec::from(out.column("rv")?.str()?), &[Some("y")]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "is_in"))]
+fn test_is_in_pushed_to_scan() -> PolarsResult<()> {
+    let q = scan_foods_csv().filter(col("category").is_in(
+        lit(Series::new("".into(), ["vegetables", "seafood"])),
+        false,
+    ));
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    assert!(out.height() > 0);
+    assert!(
+        out.column("category")?
+            .str()?
+            .into_no_null_iter()
+            .all(|c| c == "vegetables" || c == "seafood")
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "regex"))]
+fn test_str_contains_pushed_to_scan() -> PolarsResult<()> {
+    let q = scan_foods_csv().filter(col("category").str().contains(lit("^veg"), true));
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    assert!(out.height() > 0);
+    assert!(
+        out.column("category")?
+            .str()?
+            .into_no_null_iter()
+            .all(|c| c == "vegetables")
+    );
+    Ok(())
+}
@@ -192,6 +192,67 @@ fn test_shift_and_fill_non_numeric() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_lazy_strict_cast() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1i64, 300, 3]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").cast(DataType::Int8)])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i8()?), &[Some(1), None, Some(3)]);
+
+    let err = df
+        .lazy()
+        .select([col("a").strict_cast(DataType::Int8)])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("first failure at row 1"));
+    Ok(())
+}
+
+#[test]
+fn test_shift_strings_and_lists() -> PolarsResult<()> {
+    let out = df![
+        "s" => ["a", "b", "c"],
+        "l" => [
+            Series::new("".into(), [1, 2]),
+            Series::new("".into(), [3]),
+            Series::new("".into(), [4, 5, 6])
+        ],
+        "n" => [10, 20, 30]
+    ]?
+    .lazy()
+    .select([
+        col("s").shift_and_fill(lit(1), lit("start")).alias("lag"),
+        col("s").shift_and_fill(lit(-2), lit("end")).alias("lead"),
+        col("l").shift(lit(-1)),
+        // The fill value expression is evaluated once and broadcast.
+        col("n").shift_and_fill(lit(2), col("n").max()),
+    ])
+    .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("lag")?.str()?),
+        &[Some("start"), Some("a"), Some("b")]
+    );
+    assert_eq!(
+        Vec::from(out.column("lead")?.str()?),
+        &[Some("c"), Some("end"), Some("end")]
+    );
+    let l = out.column("l")?.list()?;
+    assert_eq!(Vec::from(l.get_as_series(0).unwrap().i32()?), &[Some(3)]);
+    assert!(l.get_as_series(2).is_none());
+    assert_eq!(
+        Vec::from(out.column("n")?.i32()?),
+        &[Some(30), Some(30), Some(10)]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_lazy_ternary_and_predicates() {
     let df = get_df();
@@ -676,6 +737,56 @@ fn test_type_coercion() {
     };
 }
 
+#[test]
+fn test_type_coercion_binary() -> PolarsResult<()> {
+    let df = df! {
+        "int" => &[1, 2, 3],
+        "str" => &["a", "b", "c"]
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            (col("int") + lit(1.5)).alias("sum"),
+            col("int").gt(lit(1.5)),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.f64()?),
+        &[Some(2.5), Some(3.5), Some(4.5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("int")?.bool()?),
+        &[Some(false), Some(true), Some(true)]
+    );
+
+    // Incompatible types fail while resolving the plan, not while executing it.
+    let err = df
+        .clone()
+        .lazy()
+        .select([col("str") + col("int")])
+        .collect_schema()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+    assert!(err.to_string().contains("(got str + i32)"));
+
+    #[cfg(feature = "dtype-categorical")]
+    {
+        let out = df
+            .lazy()
+            .select([col("str")
+                .cast(DataType::from_categories(Categories::global()))
+                .eq(lit("b"))])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("str")?.bool()?),
+            &[Some(false), Some(true), Some(false)]
+        );
+    }
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "csv")]
 fn test_lazy_partition_agg() {
@@ -1273,6 +1384,34 @@ fn test_regex_selection() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "regex")]
+fn test_regex_and_wildcard_aggregations_keep_names() -> PolarsResult<()> {
+    let df = df![
+    "id" => [1, 2, 3],
+    "x_1" => [1, 2, 3],
+    "x_22" => [10, 20, 30],
+    "x_a" => [0, 0, 0]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("^x_\\d+$").sum()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["x_1", "x_22"]);
+    assert_eq!(Vec::from(out.column("x_1")?.i32()?), &[Some(6)]);
+    assert_eq!(Vec::from(out.column("x_22")?.i32()?), &[Some(60)]);
+
+    let out = df
+        .lazy()
+        .select([all().exclude_cols(["id"]).as_expr().max()])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["x_1", "x_22", "x_a"]);
+    assert_eq!(out.height(), 1);
+    Ok(())
+}
+
 #[test]
 fn test_sort_by() -> PolarsResult<()> {
     let df = df![
@@ -2010,3 +2149,70 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_map_output_type_in_schema() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6]
+    ]?;
+
+    let q = df
+        .lazy()
+        .with_column(col("a").map(
+            |c| c.cast(&DataType::String),
+            |_, f| Ok(Field::new(f.name().clone(), DataType::String)),
+        ))
+        .filter(col("a").eq(lit("2")))
+        .select([col("a"), col("b")]);
+
+    let schema = q.clone().collect_schema()?;
+    assert_eq!(schema.get("a"), Some(&DataType::String));
+
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("a")?.str()?), &[Some("2")]);
+    assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(5)]);
+    Ok(())
+}
+
+#[test]
+fn test_fetch() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2, 1, 2],
+        "v" => [1, 2, 3, 4, 5, 6]
+    ]?;
+
+    let out = df.clone().lazy().fetch(3)?;
+    assert!(out.equals(&df.head(Some(3))));
+
+    // Aggregations only see the fetched rows.
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("v").sum()])
+        .fetch(3)?;
+    assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(3), Some(3)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_fetch_scan() -> PolarsResult<()> {
+    let out = scan_foods_csv()
+        .select([col("category"), col("calories")])
+        .fetch(4)?;
+    assert_eq!(out.shape(), (4, 2));
+
+    // The filter is pushed into the scan by the optimizer, but still runs on the fetched rows
+    // only. Only one of the first four rows matches, more rows match further down the file.
+    let fetched = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .fetch(4)?;
+    let expected = scan_foods_csv()
+        .limit(4)
+        .filter(col("calories").gt(lit(100)))
+        .collect()?;
+    assert_eq!(fetched.height(), 1);
+    assert!(fetched.equals(&expected));
+    Ok(())
+}
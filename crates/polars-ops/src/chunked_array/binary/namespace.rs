@@ -220,3 +220,71 @@ pub trait BinaryNameSpaceImpl: AsBinary {
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binary_keys() -> PolarsResult<()> {
+        let a = [0xffu8; 32];
+        let b = [0x00u8; 32];
+        let ca = BinaryChunked::from_slice(
+            PlSmallStr::from_static("hash"),
+            &[&a[..], &b[..], &a[..], &[0xc3, 0x28][..]],
+        );
+        assert_eq!(
+            Vec::from(&ca.size_bytes()),
+            &[Some(32), Some(32), Some(32), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(&ca.equal(&ca.shift(2))),
+            &[None, None, Some(true), Some(false)]
+        );
+
+        let s = ca.clone().into_series();
+        assert_eq!(s.group_tuples(true, false)?.len(), 3);
+        let sorted = ca.sort(false);
+        assert_eq!(sorted.get(0), Some(&b[..]));
+        assert_eq!(sorted.get(3), Some(&a[..]));
+
+        // Not valid utf8, a strict cast to `String` raises.
+        assert!(s.strict_cast(&DataType::String).is_err());
+        let valid = BinaryChunked::from_slice(PlSmallStr::from_static("hash"), &[b"abc"]);
+        let out = valid.into_series().strict_cast(&DataType::String)?;
+        assert_eq!(Vec::from(out.str()?), &[Some("abc")]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "binary_encoding")]
+    fn test_binary_encoding_round_trip() -> PolarsResult<()> {
+        let ca = BinaryChunked::from_slice_options(
+            PlSmallStr::from_static("blob"),
+            &[Some(&[0xde, 0xad, 0xbe, 0xef][..]), None, Some(&[][..])],
+        );
+
+        let hex = ca.hex_encode();
+        assert_eq!(Vec::from(hex.str()?), &[Some("deadbeef"), None, Some("")]);
+        let decoded = hex.str()?.as_binary().hex_decode(true)?;
+        assert!(
+            decoded
+                .into_series()
+                .equals_missing(&ca.clone().into_series())
+        );
+
+        let b64 = ca.base64_encode();
+        assert_eq!(Vec::from(b64.str()?), &[Some("3q2+7w=="), None, Some("")]);
+        let decoded = b64.str()?.as_binary().base64_decode(true)?;
+        assert!(
+            decoded
+                .into_series()
+                .equals_missing(&ca.clone().into_series())
+        );
+
+        let invalid = BinaryChunked::from_slice(PlSmallStr::from_static("blob"), &[b"zz"]);
+        assert!(invalid.hex_decode(true).is_err());
+        assert_eq!(invalid.hex_decode(false)?.null_count(), 1);
+        Ok(())
+    }
+}
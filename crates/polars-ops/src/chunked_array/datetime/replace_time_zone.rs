@@ -162,3 +162,80 @@ pub fn impl_replace_time_zone(
         ),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn naive(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn utc_ms(s: &str) -> i64 {
+        naive(s).and_utc().timestamp_millis()
+    }
+
+    fn local(values: &[&str]) -> DatetimeChunked {
+        DatetimeChunked::from_naive_datetime(
+            PlSmallStr::from_static("dt"),
+            values.iter().map(|s| naive(s)),
+            TimeUnit::Milliseconds,
+        )
+    }
+
+    #[test]
+    fn test_replace_time_zone_dst() -> PolarsResult<()> {
+        let amsterdam = TimeZone::from_chrono(&chrono_tz::Europe::Amsterdam);
+        let ambiguous = |values: &[&str]| StringChunked::from_slice(PlSmallStr::EMPTY, values);
+        let raise = ambiguous(&["raise"]);
+
+        // Summer time is UTC+2.
+        let out = replace_time_zone(
+            &local(&["2021-07-01 12:00:00"]),
+            Some(&amsterdam),
+            &raise,
+            NonExistent::Raise,
+        )?;
+        assert_eq!(out.time_zone().as_ref(), Some(&amsterdam));
+        assert_eq!(out.physical().get(0), Some(utc_ms("2021-07-01 10:00:00")));
+        let back = replace_time_zone(&out, None, &raise, NonExistent::Raise)?;
+        assert_eq!(back.physical().get(0), Some(utc_ms("2021-07-01 12:00:00")));
+
+        // 02:30 happens twice when the clocks go back.
+        let fall_back = local(&["2021-10-31 02:30:00", "2021-10-31 02:30:00"]);
+        assert!(
+            replace_time_zone(&fall_back, Some(&amsterdam), &raise, NonExistent::Raise).is_err()
+        );
+        let out = replace_time_zone(
+            &fall_back,
+            Some(&amsterdam),
+            &ambiguous(&["earliest", "latest"]),
+            NonExistent::Raise,
+        )?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(utc_ms("2021-10-31 00:30:00")),
+                Some(utc_ms("2021-10-31 01:30:00"))
+            ]
+        );
+
+        // 02:30 does not exist when the clocks go forward.
+        let spring_forward = local(&["2021-03-28 02:30:00", "2021-03-28 03:30:00"]);
+        assert!(
+            replace_time_zone(
+                &spring_forward,
+                Some(&amsterdam),
+                &raise,
+                NonExistent::Raise
+            )
+            .is_err()
+        );
+        let out = replace_time_zone(&spring_forward, Some(&amsterdam), &raise, NonExistent::Null)?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[None, Some(utc_ms("2021-03-28 01:30:00"))]
+        );
+        Ok(())
+    }
+}
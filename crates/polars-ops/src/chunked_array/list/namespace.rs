@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::fmt::Write;
 
-use arrow::array::ValueSize;
+use arrow::array::{Array, Utf8ViewArray, ValueSize};
 #[cfg(feature = "list_gather")]
 use num_traits::ToPrimitive;
 #[cfg(feature = "list_gather")]
@@ -104,29 +104,42 @@ pub trait ListNameSpaceImpl: AsList {
         let mut buf = String::with_capacity(128);
         let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
 
-        ca.for_each_amortized(|opt_s| {
-            let opt_val = opt_s.and_then(|s| {
-                // make sure that we don't write values of previous iteration
-                buf.clear();
-                let ca = s.as_ref().str().unwrap();
+        // Work on the offsets and values directly, so that we don't create a `Series` per row.
+        for arr in ca.downcast_iter() {
+            let values = arr
+                .values()
+                .as_any()
+                .downcast_ref::<Utf8ViewArray>()
+                .unwrap();
 
-                if ca.null_count() != 0 && !ignore_nulls {
-                    return None;
+            for (i, (start, length)) in arr.offsets().offset_and_length_iter().enumerate() {
+                if !arr.is_valid(i) {
+                    builder.append_null();
+                    continue;
                 }
 
-                for arr in ca.downcast_iter() {
-                    for val in arr.non_null_values_iter() {
-                        buf.write_str(val).unwrap();
-                        buf.write_str(separator).unwrap();
+                // make sure that we don't write values of previous iteration
+                buf.clear();
+                let mut has_nulls = false;
+                for idx in start..start + length {
+                    match values.get(idx) {
+                        Some(val) => {
+                            buf.write_str(val).unwrap();
+                            buf.write_str(separator).unwrap();
+                        },
+                        None => has_nulls = true,
                     }
                 }
 
-                // last value should not have a separator, so slice that off
-                // saturating sub because there might have been nothing written.
-                Some(&buf[..buf.len().saturating_sub(separator.len())])
-            });
-            builder.append_option(opt_val)
-        });
+                if has_nulls && !ignore_nulls {
+                    builder.append_null();
+                } else {
+                    // last value should not have a separator, so slice that off
+                    // saturating sub because there might have been nothing written.
+                    builder.append_value(&buf[..buf.len().saturating_sub(separator.len())]);
+                }
+            }
+        }
         Ok(builder.finish())
     }
 
@@ -384,6 +397,29 @@ pub trait ListNameSpaceImpl: AsList {
         unsafe { s.from_physical_unchecked(ca.inner_dtype()) }
     }
 
+    /// Get the first item of every sublist, or `None` if the sublist is empty.
+    fn lst_first(&self) -> PolarsResult<Series> {
+        self.lst_get(0, true)
+    }
+
+    /// Get the last item of every sublist, or `None` if the sublist is empty.
+    fn lst_last(&self) -> PolarsResult<Series> {
+        self.lst_get(-1, true)
+    }
+
+    /// Check for every sublist whether it contains `item`.
+    ///
+    /// `item` is either a single value or has the same length as the list. A null sublist
+    /// results in a null. A null `item` checks whether the sublist contains a null if
+    /// `nulls_equal` is set, otherwise it results in a null.
+    #[cfg(feature = "is_in")]
+    fn lst_contains(&self, item: &Series, nulls_equal: bool) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_list();
+        let mut out = crate::series::is_in(item, &ca.clone().into_series(), nulls_equal)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
     #[cfg(feature = "list_gather")]
     fn lst_gather_every(&self, n: &IdxCa, offset: &IdxCa) -> PolarsResult<Series> {
         let list_ca = self.as_list();
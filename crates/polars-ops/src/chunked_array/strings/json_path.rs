@@ -311,4 +311,49 @@ mod tests {
                 .equals_missing(&c_series)
         );
     }
+
+    #[test]
+    fn test_json_path_match() {
+        let ca = StringChunked::new(
+            "json".into(),
+            [
+                Some(r#"{"event":{"user":"ann","tags":["a","b"],"n":3}}"#),
+                Some(r#"{"event":{"user":null}}"#),
+                Some(r#"{"other":1}"#),
+                Some("not json"),
+                None,
+            ],
+        );
+        let path = |p: &str| StringChunked::new("".into(), [p]);
+
+        // Strings are returned unquoted, missing keys, json nulls and parse errors are null.
+        assert_eq!(
+            Vec::from(&ca.json_path_match(&path("$.event.user")).unwrap()),
+            &[Some("ann"), None, None, None, None]
+        );
+        assert_eq!(
+            Vec::from(&ca.json_path_match(&path("$.event.tags[1]")).unwrap()),
+            &[Some("b"), None, None, None, None]
+        );
+        assert_eq!(
+            Vec::from(&ca.json_path_match(&path("$.event.n")).unwrap()),
+            &[Some("3"), None, None, None, None]
+        );
+
+        // A path per row.
+        let paths = StringChunked::new(
+            "".into(),
+            [
+                Some("$.event.n"),
+                Some("$.event.user"),
+                Some("$.other"),
+                Some("$"),
+                Some("$"),
+            ],
+        );
+        assert_eq!(
+            Vec::from(&ca.json_path_match(&paths).unwrap()),
+            &[Some("3"), None, Some("1"), None, None]
+        );
+    }
 }
@@ -38,13 +38,13 @@ fn pad_fn<'a>(
 
 fn zfill_fn<'a>(s: Option<&'a str>, length: Option<u64>, buf: &mut String) -> Option<&'a str> {
     if let (Some(s), Some(length)) = (s, length) {
-        let s_len = s.len();
+        let n_chars = s.chars().count();
         let length = length as usize;
-        if length <= s_len {
+        if length <= n_chars {
             return Some(s);
         }
         buf.clear();
-        let length = length - s_len;
+        let length = length - n_chars;
         if let Some(stripped) = s.strip_prefix('-') {
             write!(buf, "-{:0length$}{stripped}", 0,).unwrap();
         } else if let Some(stripped) = s.strip_prefix('+') {
@@ -107,3 +107,50 @@ pub(super) fn pad_end<'a>(
         infer(|opt_s, opt_len| pad_fn(opt_s, opt_len, &mut buf, fill_char, false)),
     )
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_pad_and_zfill_count_chars() {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[Some("7"), Some("-12"), Some("é"), Some("toolong"), None],
+        );
+        let length = UInt64Chunked::new("".into(), &[5u64]);
+
+        assert_eq!(
+            Vec::from(&zfill(&ca, &length)),
+            &[
+                Some("00007"),
+                Some("-0012"),
+                Some("0000é"),
+                Some("toolong"),
+                None
+            ]
+        );
+        assert_eq!(
+            Vec::from(&pad_start(&ca, &length, '*')),
+            &[
+                Some("****7"),
+                Some("**-12"),
+                Some("****é"),
+                Some("toolong"),
+                None
+            ]
+        );
+        assert_eq!(
+            Vec::from(&pad_end(&ca, &length, 'ü')),
+            &[
+                Some("7üüüü"),
+                Some("-12üü"),
+                Some("éüüüü"),
+                Some("toolong"),
+                None
+            ]
+        );
+    }
+}
@@ -188,6 +188,7 @@ where
                 );
 
                 by.for_each(|opt_by| match opt_by {
+                    Some("") => builder.append_values_iter(split_chars(s)),
                     Some(by) => builder.append_values_iter(op(s, by)),
                     _ => builder.append_null(),
                 });
@@ -223,3 +224,120 @@ where
         _ => polars_bail!(length_mismatch = "str.split", ca.len(), by.len()),
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_chars_multi_byte() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), &[Some("héllo"), Some(""), None, Some("日本")]);
+        let by = StringChunked::new("".into(), &[""]);
+        let out = split_helper(&ca, &by, str::split)?;
+
+        let lengths = out
+            .amortized_iter()
+            .map(|s| s.map(|s| s.as_ref().len()))
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, &[Some(5), Some(0), None, Some(2)]);
+
+        let exploded = out.explode(false)?;
+        assert_eq!(
+            Vec::from(exploded.str()?),
+            &[
+                Some("h"),
+                Some("é"),
+                Some("l"),
+                Some("l"),
+                Some("o"),
+                None,
+                None,
+                Some("日"),
+                Some("本")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_chars_broadcast_string() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), &["añb"]);
+        let by = StringChunked::new("".into(), &[Some(""), Some("ñ"), None]);
+        let out = split_helper(&ca, &by, str::split)?;
+
+        let exploded = out.explode(false)?;
+        assert_eq!(
+            Vec::from(exploded.str()?),
+            &[Some("a"), Some("ñ"), Some("b"), Some("a"), Some("b"), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_missing_and_trailing_separators() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[Some("NL-2021-00042"), Some("NL"), Some("a-b-"), None],
+        );
+        let by = StringChunked::new("".into(), &["-"]);
+
+        let out = split_helper(&ca, &by, str::split)?;
+        let rows = out
+            .amortized_iter()
+            .map(|s| s.map(|s| s.as_ref().len()))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, &[Some(3), Some(1), Some(3), None]);
+        assert_eq!(
+            Vec::from(out.explode(false)?.str()?),
+            &[
+                Some("NL"),
+                Some("2021"),
+                Some("00042"),
+                Some("NL"),
+                Some("a"),
+                Some("b"),
+                Some(""),
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_split_exact_and_splitn_to_struct() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            &[Some("NL-2021-00042"), Some("NL"), Some("a-b-"), None],
+        );
+        let by = StringChunked::new("".into(), &["-"]);
+        let fields = |ca: StructChunked| {
+            ca.fields_as_series()
+                .iter()
+                .map(|s| Vec::from(s.str().unwrap()))
+                .collect::<Vec<_>>()
+        };
+
+        // Missing fields are padded with nulls.
+        let out = split_to_struct(&ca, &by, 3, str::split, false)?;
+        assert_eq!(
+            fields(out),
+            &[
+                vec![Some("NL"), Some("NL"), Some("a"), None],
+                vec![Some("2021"), None, Some("b"), None],
+                vec![Some("00042"), None, Some(""), None],
+            ]
+        );
+
+        // The last field of `splitn` keeps the remainder.
+        let out = split_to_struct(&ca, &by, 2, |s, by| s.splitn(2, by), true)?;
+        assert_eq!(
+            fields(out),
+            &[
+                vec![Some("NL"), Some("NL"), Some("a"), None],
+                vec![Some("2021-00042"), None, Some("b-"), None],
+            ]
+        );
+        Ok(())
+    }
+}
@@ -98,6 +98,14 @@ pub fn substring_ternary_offsets_value(str_val: &str, offset: i64, length: u64)
         return (0, 0);
     }
 
+    // Fast-path: for ascii every byte is a char boundary.
+    if str_val.is_ascii() {
+        let len = str_val.len() as i64;
+        let start = if offset >= 0 { offset } else { len + offset };
+        let end = start.saturating_add(length.min(i64::MAX as u64) as i64);
+        return (start.clamp(0, len) as usize, end.clamp(0, len) as usize);
+    }
+
     let mut indices = str_val.char_indices().map(|(o, _)| o);
     let mut length_reduction = 0;
     let start_byte_offset = if offset >= 0 {
@@ -273,3 +281,34 @@ pub(super) fn tail(ca: &StringChunked, n: &Int64Chunked) -> PolarsResult<StringC
         },
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_substring_offsets() {
+        let slice = |s: &'static str, offset: i64, length: u64| {
+            let (start, end) = substring_ternary_offsets_value(s, offset, length);
+            &s[start..end]
+        };
+
+        for (ascii, multi_byte) in [("abcde", "ábcdé"), ("hello", "héllö")] {
+            for offset in -7..7 {
+                for length in [0, 1, 2, 4, 10, u64::MAX] {
+                    // The ascii fast path agrees with walking the chars.
+                    let a = slice(ascii, offset, length);
+                    let b = slice(multi_byte, offset, length);
+                    assert_eq!(a.chars().count(), b.chars().count(), "{offset} {length}");
+                }
+            }
+        }
+
+        assert_eq!(slice("ábcdé", 1, 3), "bcd");
+        assert_eq!(slice("ábcdé", -2, 1), "d");
+        assert_eq!(slice("ábcdé", -1, u64::MAX), "é");
+        assert_eq!(slice("abc", -5, 3), "a");
+        assert_eq!(slice("abc", -10, 5), "");
+        assert_eq!(slice("日本語", -2, u64::MAX), "本語");
+    }
+}
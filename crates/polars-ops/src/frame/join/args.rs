@@ -141,6 +141,11 @@ impl JoinArgs {
         self
     }
 
+    pub fn with_nulls_equal(mut self, nulls_equal: bool) -> Self {
+        self.nulls_equal = nulls_equal;
+        self
+    }
+
     pub fn suffix(&self) -> &PlSmallStr {
         const DEFAULT: &PlSmallStr = &PlSmallStr::from_static("_right");
         self.suffix.as_ref().unwrap_or(DEFAULT)
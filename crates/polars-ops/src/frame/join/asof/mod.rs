@@ -359,3 +359,83 @@ pub trait AsofJoin: IntoDf {
 }
 
 impl AsofJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_asof_unsorted_key_err() -> PolarsResult<()> {
+        let a = df!["a" => [3, 1, 2]]?;
+        let b = df!["a" => [1, 2, 3]]?;
+        let left_key = a.column("a")?.as_materialized_series();
+        let right_key = b.column("a")?.as_materialized_series();
+
+        let out = a._join_asof(
+            &b,
+            left_key,
+            right_key,
+            AsofStrategy::Backward,
+            None,
+            None,
+            None,
+            true,
+            true,
+            true,
+        );
+        assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+
+        // Without the check the join is computed, the result is not meaningful though.
+        let out = a._join_asof(
+            &b,
+            left_key,
+            right_key,
+            AsofStrategy::Backward,
+            None,
+            None,
+            None,
+            true,
+            true,
+            false,
+        );
+        assert!(out.is_ok());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_asof_datetime_tolerance() -> PolarsResult<()> {
+        let time = |name: &str, v: &[i64]| {
+            Int64Chunked::new(name.into(), v)
+                .into_datetime(TimeUnit::Milliseconds, None)
+                .into_column()
+        };
+        let a = DataFrame::new(vec![
+            time("time", &[1000, 2000, 3000, 4500]),
+            Column::new("id".into(), [0, 1, 2, 3]),
+        ])?;
+        let b = DataFrame::new(vec![
+            time("time", &[900, 2000, 4000]),
+            Column::new("val".into(), [1, 2, 3]),
+        ])?;
+
+        let out = a._join_asof(
+            &b,
+            a.column("time")?.as_materialized_series(),
+            b.column("time")?.as_materialized_series(),
+            AsofStrategy::Backward,
+            Some(AnyValue::Int64(500)),
+            None,
+            None,
+            true,
+            true,
+            true,
+        )?;
+        assert_eq!(out.get_column_names_str(), &["time", "id", "val"]);
+        assert_eq!(
+            Vec::from(out.column("val")?.i32()?),
+            &[Some(1), Some(2), None, Some(3)]
+        );
+        Ok(())
+    }
+}
@@ -80,14 +80,15 @@ fn cross_join_dfs<'a>(
         core::mem::swap(&mut df_self, &mut other);
     }
 
+    // Compute the output height in a wider type, so that neither the heights nor
+    // their product can silently wrap when cast to `IdxSize`.
+    let total_rows = df_self.height() as u128 * other.height() as u128;
+    let Ok(total_rows) = IdxSize::try_from(total_rows) else {
+        polars_bail!(bigidx, ctx = "cross join", size = total_rows);
+    };
+    // Both heights are at most `total_rows` as neither frame is empty.
     let n_rows_left = df_self.height() as IdxSize;
     let n_rows_right = other.height() as IdxSize;
-    let Some(total_rows) = n_rows_left.checked_mul(n_rows_right) else {
-        polars_bail!(
-            ComputeError: "cross joins would produce more rows than fits into 2^32; \
-            consider compiling with polars-big-idx feature, or set 'streaming'"
-        );
-    };
 
     // the left side has the Nth row combined with every row from right.
     // So let's say we have the following no. of rows
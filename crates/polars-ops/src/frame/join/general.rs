@@ -26,7 +26,7 @@ pub fn _finish_join(
     });
 
     let mut rename_strs = Vec::with_capacity(df_right.width());
-    let right_names = df_right.schema();
+    let right_names = df_right.schema().clone();
 
     for name in right_names.iter_names() {
         if left_names.contains(name) {
@@ -38,12 +38,21 @@ pub fn _finish_join(
 
     for name in rename_strs {
         let new_name = _join_suffix_name(name.as_str(), suffix.as_str());
-        // Safety: IR resolving should guarantee this passes
+        // The eager API doesn't go through IR resolving, so check that the
+        // suffixed name doesn't collide with an existing column.
+        polars_ensure!(
+            !left_names.contains(&new_name) && !right_names.contains(&new_name),
+            Duplicate: "column with name '{}' already exists\n\n\
+            You may want to try:\n\
+            - renaming the column prior to joining\n\
+            - using the `suffix` parameter to specify a suffix different to the default one ('_right')",
+            new_name
+        );
         df_right.rename(&name, new_name.clone()).unwrap();
     }
 
     drop(left_names);
-    // Safety: IR resolving should guarantee this passes
+    // SAFETY: the suffixed names are checked above, so all names are unique.
     unsafe { df_left.hstack_mut_unchecked(df_right.get_columns()) };
     Ok(df_left)
 }
@@ -135,6 +135,65 @@ where
     )
 }
 
+/// Do a stable pivot operation with several aggregation functions on the values columns.
+///
+/// Every aggregation is given a name, which is appended to the pivoted columns it produces,
+/// e.g. `{column}{separator}{name}`. The pivoted columns of each aggregation are placed after
+/// the ones of the previous aggregation.
+///
+/// Every aggregation runs a full pivot, including the group_by on `index` and `on`, so the cost
+/// grows linearly with the number of aggregations.
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_stable_multiple_aggs<I0, I1, I2, S0, S1, S2>(
+    pivot_df: &DataFrame,
+    on: I0,
+    index: Option<I1>,
+    values: Option<I2>,
+    sort_columns: bool,
+    aggs: Vec<(PlSmallStr, PivotAgg)>,
+    separator: Option<&str>,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: Into<PlSmallStr>,
+    S1: Into<PlSmallStr>,
+    S2: Into<PlSmallStr>,
+{
+    polars_ensure!(!aggs.is_empty(), InvalidOperation: "no aggregations provided in `pivot`");
+    let on = on.into_iter().map(Into::into).collect::<Vec<_>>();
+    let (index, values) = assign_remaining_columns(pivot_df, &on, index, values)?;
+    let sep = separator.unwrap_or("_");
+
+    let mut final_cols: Vec<Column> = vec![];
+    for (agg_name, agg_fn) in aggs {
+        let out = pivot_impl(
+            pivot_df,
+            &on,
+            &index,
+            &values,
+            Some(agg_fn),
+            sort_columns,
+            true,
+            separator,
+        )?;
+        let mut cols = out.take_columns();
+        let mut pivoted = cols.split_off(index.len());
+        for c in pivoted.iter_mut() {
+            c.rename(format_pl_smallstr!("{}{sep}{agg_name}", c.name()));
+        }
+
+        // The stable group_by guarantees that every aggregation produces the same rows.
+        if final_cols.is_empty() {
+            final_cols = cols;
+        }
+        final_cols.extend(pivoted);
+    }
+
+    DataFrame::new(final_cols)
+}
+
 /// Ensure both `index` and `values` are populated with `Vec<String>`.
 ///
 /// - If `index` is None, assign columns not in `on` and `values` to it.
@@ -291,4 +291,30 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_unpivot_supertype() -> PolarsResult<()> {
+        let df = df!("id" => &["a", "b"],
+         "x" => &[1i32, 2],
+         "y" => &[0.5f64, 1.5]
+        )?;
+
+        // i32 and f64 value columns are cast to their supertype
+        let args = UnpivotArgsIR {
+            on: vec![],
+            index: vec!["id".into()],
+            ..Default::default()
+        };
+        let unpivoted = df.unpivot2(args)?;
+        assert_eq!(unpivoted.column("value")?.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(unpivoted.column("value")?.f64()?),
+            &[Some(1.0), Some(2.0), Some(0.5), Some(1.5)]
+        );
+        assert_eq!(
+            Vec::from(unpivoted.column("variable")?.str()?),
+            &[Some("x"), Some("x"), Some("y"), Some("y")]
+        );
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use polars_core::prelude::*;
 
 /// Convert numerical values to their absolute value.
+///
+/// Signed integers keep their dtype, so the minimum value wraps to itself, e.g. `abs(i32::MIN)`
+/// is `i32::MIN`. Cast to a wider type first if that value can occur.
 pub fn abs(s: &Series) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
@@ -35,3 +38,30 @@ pub fn abs(s: &Series) -> PolarsResult<Series> {
     };
     Ok(out)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_abs() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(-3i32), None, Some(2), Some(i32::MIN)]);
+        let out = abs(&s)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), None, Some(2), Some(i32::MIN)]
+        );
+
+        let out = abs(&s.cast(&DataType::Int64)?)?;
+        assert_eq!(out.i64()?.get(3), Some(-(i32::MIN as i64)));
+
+        let s = Series::new("a".into(), [-1.5f64, 0.0, 2.5]);
+        assert_eq!(
+            Vec::from(abs(&s)?.f64()?),
+            &[Some(1.5), Some(0.0), Some(2.5)]
+        );
+
+        assert!(abs(&Series::new("a".into(), ["x"])).is_err());
+        Ok(())
+    }
+}
@@ -208,3 +208,29 @@ where
         }
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clip() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(-5i32), None, Some(3), Some(10)]);
+        let min = Series::new("min".into(), [0i32]);
+        let max = Series::new("max".into(), [5i32]);
+
+        let out = clip(&s, &min, &max)?;
+        assert_eq!(out.dtype(), &DataType::Int32);
+        assert_eq!(Vec::from(out.i32()?), &[Some(0), None, Some(3), Some(5)]);
+
+        let out = clip_min(&s, &min)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(0), None, Some(3), Some(10)]);
+        let out = clip_max(&s, &max)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(-5), None, Some(3), Some(5)]);
+
+        // Bounds that can't be cast to the input dtype are rejected.
+        assert!(clip_max(&s, &Series::new("max".into(), ["x"])).is_err());
+        assert!(clip(&Series::new("a".into(), ["x"]), &min, &max).is_err());
+        Ok(())
+    }
+}
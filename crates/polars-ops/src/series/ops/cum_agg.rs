@@ -3,7 +3,7 @@ use std::ops::{AddAssign, Mul};
 use arity::unary_elementwise_values;
 use arrow::array::{Array, BooleanArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder};
-use num_traits::{Bounded, One, Zero};
+use num_traits::{Bounded, CheckedMul, One, Zero};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::{CustomIterTools, NoNull};
@@ -72,7 +72,7 @@ fn cum_scan_numeric<T, F>(
 where
     T: PolarsNumericType,
     ChunkedArray<T>: FromIterator<Option<T::Native>>,
-    F: Fn(&mut T::Native, Option<T::Native>) -> Option<Option<T::Native>>,
+    F: FnMut(&mut T::Native, Option<T::Native>) -> Option<Option<T::Native>>,
 {
     let out: ChunkedArray<T> = match reverse {
         false => ca.iter().scan(init, update).collect_trusted(),
@@ -253,6 +253,39 @@ where
     cum_scan_numeric(ca, reverse, init, det_prod)
 }
 
+/// Cumulative product of integers that errors instead of silently wrapping on overflow.
+fn cum_prod_checked<T>(
+    ca: &ChunkedArray<T>,
+    reverse: bool,
+    init: Option<T::Native>,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedMul,
+    ChunkedArray<T>: FromIterator<Option<T::Native>>,
+{
+    let init = init.unwrap_or(T::Native::one());
+    let mut overflowed = false;
+    let out = cum_scan_numeric(ca, reverse, init, |state, v| match v {
+        Some(v) => match state.checked_mul(&v) {
+            Some(prod) => {
+                *state = prod;
+                Some(Some(prod))
+            },
+            None => {
+                overflowed = true;
+                Some(None)
+            },
+        },
+        None => Some(None),
+    });
+    polars_ensure!(
+        !overflowed,
+        ComputeError: "overflow in 'cum_prod' on dtype {}; consider casting to Float64 first", ca.dtype()
+    );
+    Ok(out)
+}
+
 pub fn cum_prod_with_init(
     s: &Series,
     reverse: bool,
@@ -262,14 +295,14 @@ pub fn cum_prod_with_init(
     let out = match s.dtype() {
         Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => {
             let s = s.cast(&Int64)?;
-            cum_prod_numeric(s.i64()?, reverse, init.extract()).into_series()
+            cum_prod_checked(s.i64()?, reverse, init.extract())?.into_series()
         },
-        Int64 => cum_prod_numeric(s.i64()?, reverse, init.extract()).into_series(),
-        UInt64 => cum_prod_numeric(s.u64()?, reverse, init.extract()).into_series(),
+        Int64 => cum_prod_checked(s.i64()?, reverse, init.extract())?.into_series(),
+        UInt64 => cum_prod_checked(s.u64()?, reverse, init.extract())?.into_series(),
         #[cfg(feature = "dtype-i128")]
-        Int128 => cum_prod_numeric(s.i128()?, reverse, init.extract()).into_series(),
+        Int128 => cum_prod_checked(s.i128()?, reverse, init.extract())?.into_series(),
         #[cfg(feature = "dtype-u128")]
-        UInt128 => cum_prod_numeric(s.u128()?, reverse, init.extract()).into_series(),
+        UInt128 => cum_prod_checked(s.u128()?, reverse, init.extract())?.into_series(),
         Float32 => cum_prod_numeric(s.f32()?, reverse, init.extract()).into_series(),
         Float64 => cum_prod_numeric(s.f64()?, reverse, init.extract()).into_series(),
         dt => polars_bail!(opq = cum_prod, dt),
@@ -280,7 +313,8 @@ pub fn cum_prod_with_init(
 /// Get an array with the cumulative product computed at every element.
 ///
 /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16, Int32, UInt32}` the `Series` is
-/// first cast to `Int64` to prevent overflow issues.
+/// first cast to `Int64` to prevent overflow issues. Integer products that still overflow
+/// return an error.
 pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
     cum_prod_with_init(s, reverse, &AnyValue::Null)
 }
@@ -463,3 +497,56 @@ fn cum_count_no_nulls(name: PlSmallStr, len: usize, reverse: bool, init: IdxSize
     ca.rename(name);
     ca.into_series()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cum_agg_nulls_and_chunks() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), [Some(2i32), None, Some(1)]);
+        s.append(&Series::new("a".into(), [Some(3i32), Some(-1)]))?;
+        assert_eq!(s.n_chunks(), 2);
+
+        // Nulls stay null but don't reset the running value, also across chunks.
+        let out = cum_sum(&s, false)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(2), None, Some(3), Some(6), Some(5)]
+        );
+        let out = cum_sum(&s, true)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(5), None, Some(3), Some(2), Some(-1)]
+        );
+        let out = cum_min(&s, false)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(2), None, Some(1), Some(1), Some(-1)]
+        );
+        let out = cum_max(&s, true)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), None, Some(3), Some(3), Some(-1)]
+        );
+
+        // Small integers are widened before multiplying.
+        let out = cum_prod(&s, false)?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(2), None, Some(2), Some(6), Some(-6)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cum_prod_overflow() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [i64::MAX / 2, 3, 1]);
+        assert!(cum_prod(&s, false).is_err());
+        assert!(cum_prod(&s.cast(&DataType::Float64)?, false).is_ok());
+
+        let s = Series::new("a".into(), [u64::MAX, 1, 1]);
+        assert_eq!(Vec::from(cum_prod(&s, true)?.u64()?)[0], Some(u64::MAX));
+        Ok(())
+    }
+}
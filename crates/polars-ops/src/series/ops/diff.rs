@@ -14,13 +14,54 @@ pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Ser
         NullBehavior::Ignore => &s - &s.shift(n),
         NullBehavior::Drop if n < 0 => {
             let n = -n as usize;
-            let len = s.len() - n;
+            let len = s.len().saturating_sub(n);
             &s.slice(0, len) - &s.slice(n as i64, len)
         },
         NullBehavior::Drop => {
             let n = n as usize;
-            let len = s.len() - n;
+            let len = s.len().saturating_sub(n);
             &s.slice(n as i64, len) - &s.slice(0, len)
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [1i32, 4, 9, 16]);
+
+        let out = diff(&s, 1, NullBehavior::Ignore)?;
+        assert_eq!(Vec::from(out.i32()?), &[None, Some(3), Some(5), Some(7)]);
+        let out = diff(&s, -2, NullBehavior::Ignore)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(-8), Some(-12), None, None]);
+
+        let out = diff(&s, 2, NullBehavior::Drop)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(8), Some(12)]);
+        let out = diff(&s, -1, NullBehavior::Drop)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(-3), Some(-5), Some(-7)]);
+        assert!(diff(&s, 5, NullBehavior::Drop)?.is_empty());
+
+        // Unsigned integers are widened so the difference can be negative.
+        let s = Series::new("a".into(), [3u32, 1]);
+        let out = diff(&s, 1, NullBehavior::Ignore)?;
+        assert_eq!(Vec::from(out.i64()?), &[None, Some(-2)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_diff_datetime() -> PolarsResult<()> {
+        let s = Series::new("t".into(), [0i64, 1_500, 4_000])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let out = diff(&s, 1, NullBehavior::Ignore)?;
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(
+            Vec::from(out.to_physical_repr().i64()?),
+            &[None, Some(1_500), Some(2_500)]
+        );
+        Ok(())
+    }
+}
@@ -38,7 +38,6 @@ pub fn ewm_mean(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
             );
             Series::try_from((s.name().clone(), Box::new(result) as ArrayRef))
         },
-        dt if cfg!(debug_assertions) => panic!("{:?}", dt),
         _ => ewm_mean(&s.cast(&DataType::Float64)?, options),
     }
 }
@@ -104,3 +103,61 @@ pub fn ewm_var(s: &Series, options: EWMOptions) -> PolarsResult<Series> {
         _ => ewm_var(&s.cast(&DataType::Float64)?, options),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(s: &Series, expected: &[Option<f64>]) {
+        let out = Vec::from(s.f64().unwrap());
+        assert_eq!(out.len(), expected.len());
+        for (a, b) in out.iter().zip(expected) {
+            match (a, b) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 1e-12, "{a} != {b}"),
+                (a, b) => assert_eq!(a, b),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ewm_integers_and_nulls() -> PolarsResult<()> {
+        // Integers are computed as `Float64`.
+        let s = Series::new("a".into(), [1i32, 2, 3]);
+        let options = EWMOptions::default().and_span(3);
+        assert_close(
+            &ewm_mean(&s, options)?,
+            &[Some(1.0), Some(5.0 / 3.0), Some(4.25 / 1.75)],
+        );
+        assert_close(
+            &ewm_mean(&s, options.and_adjust(false))?,
+            &[Some(1.0), Some(1.5), Some(2.25)],
+        );
+
+        // Skipped nulls don't decay the weight of earlier values, otherwise they do.
+        let s = Series::new("a".into(), [Some(1i64), None, Some(3)]);
+        let out = ewm_mean(&s, options)?;
+        assert_close(&out.slice(2, 1), &[Some(3.5 / 1.5)]);
+        let out = ewm_mean(&s, options.and_ignore_nulls(false))?;
+        assert_close(&out.slice(2, 1), &[Some(3.25 / 1.25)]);
+        assert_eq!(out.f64()?.get(1), None);
+
+        let s = Series::new("a".into(), [1i32, 3, 2, 5]);
+        let var = ewm_var(&s, options)?;
+        let std = ewm_std(&s, options)?;
+        assert_eq!(var.dtype(), &DataType::Float64);
+        let squared = (&std * &std)?;
+        assert_close(&squared, &Vec::from(var.f64()?));
+
+        assert!(
+            ewm_std(
+                &s,
+                EWMOptions {
+                    alpha: 1.5,
+                    ..options
+                }
+            )
+            .is_err()
+        );
+        Ok(())
+    }
+}
@@ -353,7 +353,7 @@ pub fn coalesce_columns(s: &[Column]) -> PolarsResult<Column> {
     polars_ensure!(!s.is_empty(), NoData: "cannot coalesce empty list");
     let mut out = s[0].clone();
     for s in s {
-        if !out.null_count() == 0 {
+        if out.null_count() == 0 {
             return Ok(out);
         } else {
             let mask = out.is_not_null();
@@ -407,4 +407,51 @@ mod tests {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sum_horizontal_supertype_null_strategy() {
+        let a = Column::new("a".into(), [1i32, 2, 3]);
+        let b = Column::new("b".into(), [Some(0.5f64), None, Some(1.5)]);
+        let c = Column::new("c".into(), [Some(1i64), Some(1), None]);
+
+        let df = DataFrame::new(vec![a, b, c]).unwrap();
+        assert_eq!(
+            Vec::from(
+                df.sum_horizontal(NullStrategy::Ignore)
+                    .unwrap()
+                    .unwrap()
+                    .f64()
+                    .unwrap()
+            ),
+            &[Some(2.5), Some(3.0), Some(4.5)]
+        );
+        assert_eq!(
+            Vec::from(
+                df.sum_horizontal(NullStrategy::Propagate)
+                    .unwrap()
+                    .unwrap()
+                    .f64()
+                    .unwrap()
+            ),
+            &[Some(2.5), None, None]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_coalesce_columns() {
+        let a = Column::new("a".into(), [Some(1), None, None]);
+        let b = Column::new("b".into(), [Some(4), Some(5), None]);
+        let c = Column::new("c".into(), [7, 8, 9]);
+
+        let out = coalesce_columns(&[a, b.clone(), c.clone()]).unwrap();
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(1), Some(5), Some(9)]);
+
+        // The first column without nulls is returned as is.
+        let out = coalesce_columns(&[c, b]).unwrap();
+        assert_eq!(out.name().as_str(), "c");
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(7), Some(8), Some(9)]);
+    }
 }
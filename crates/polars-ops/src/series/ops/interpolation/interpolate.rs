@@ -320,4 +320,49 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_interpolate_edge_cases() -> PolarsResult<()> {
+        // All null and a single valid value have nothing to interpolate between.
+        let s = Series::new("".into(), [None::<f64>, None, None]);
+        let out = interpolate(&s, InterpolationMethod::Linear);
+        assert_eq!(out.null_count(), 3);
+
+        let s = Series::new("".into(), [None, Some(2.0f64), None]);
+        let out = interpolate(&s, InterpolationMethod::Linear);
+        assert_eq!(Vec::from(out.f64()?), &[None, Some(2.0), None]);
+
+        // A null run spanning a chunk boundary.
+        let mut s = Series::new("".into(), [Some(0.0f64), None, None]);
+        s.append(&Series::new("".into(), [None, None, Some(5.0f64), None]))?;
+        assert_eq!(s.n_chunks(), 2);
+        let out = interpolate(&s, InterpolationMethod::Linear);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[
+                Some(0.0),
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                Some(4.0),
+                Some(5.0),
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_interpolate_datetime() -> PolarsResult<()> {
+        let dtype = DataType::Datetime(TimeUnit::Milliseconds, None);
+        let s = Series::new("".into(), [Some(0i64), None, None, Some(3_000)]).cast(&dtype)?;
+        let out = interpolate(&s, InterpolationMethod::Linear);
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(
+            Vec::from(out.to_physical_repr().i64()?),
+            &[Some(0), Some(1_000), Some(2_000), Some(3_000)]
+        );
+        Ok(())
+    }
 }
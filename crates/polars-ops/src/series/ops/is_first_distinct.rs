@@ -147,3 +147,32 @@ pub fn is_first_distinct(s: &Series) -> PolarsResult<BooleanChunked> {
     };
     Ok(out)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_first_distinct() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some("x"), Some("y"), None, Some("x"), None, Some("z")],
+        );
+        let mask = is_first_distinct(&s)?;
+        assert_eq!(
+            Vec::from(&mask),
+            &[
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true)
+            ]
+        );
+
+        // Filtering on the mask is the same as a stable unique.
+        assert!(s.filter(&mask)?.equals_missing(&s.unique_stable()?));
+        Ok(())
+    }
+}
@@ -21,3 +21,27 @@ pub fn pct_change(s: &Series, n: &Series) -> PolarsResult<Series> {
         Ok(Series::full_null(s.name().clone(), s.len(), s.dtype()))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pct_change() -> PolarsResult<()> {
+        let s = Series::new("price".into(), [1i32, 2, 4, 3]);
+
+        let out = pct_change(&s, &Series::new("n".into(), [1]))?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[None, Some(1.0), Some(1.0), Some(-0.25)]
+        );
+        let out = pct_change(&s, &Series::new("n".into(), [-2]))?;
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(-0.75), Some(-1.0 / 3.0), None, None]
+        );
+
+        assert!(pct_change(&s, &Series::new("n".into(), [1, 2])).is_err());
+        Ok(())
+    }
+}
@@ -310,6 +310,16 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "random")]
+    fn test_rank_random_seed() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[1, 1, 1, 1, 2, 2, 2, 2]);
+        let a = rank(&s, RankMethod::Random, false, Some(7));
+        let b = rank(&s, RankMethod::Random, false, Some(7));
+        assert!(a.equals(&b));
+        Ok(())
+    }
+
     #[test]
     fn test_rank_all_null() -> PolarsResult<()> {
         let s = UInt32Chunked::new("".into(), &[None, None, None]).into_series();
@@ -75,6 +75,11 @@ pub fn search_sorted(
         },
         dt if dt.is_primitive_numeric() => {
             let search_values = search_values.to_physical_repr();
+            polars_ensure!(
+                search_values.dtype() == phys_dtype,
+                SchemaMismatch: "'search_sorted' on dtype {} got search values of dtype {}",
+                original_dtype, search_values.dtype()
+            );
 
             let idx = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -102,3 +107,40 @@ pub fn search_sorted(
         _ => polars_bail!(opq = search_sorted, original_dtype),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_sorted() -> PolarsResult<()> {
+        let edges = Series::new("edges".into(), [10i64, 20, 20, 30]);
+        let values = Series::new("v".into(), [Some(5i64), Some(20), Some(25), Some(40), None]);
+
+        let out = search_sorted(&edges, &values, SearchSortedSide::Left, false)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0), Some(1), Some(3), Some(4), Some(0)]
+        );
+        let out = search_sorted(&edges, &values, SearchSortedSide::Right, false)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0), Some(3), Some(3), Some(4), Some(0)]
+        );
+
+        let edges = Series::new("edges".into(), ["b", "d", "f"]);
+        let values = Series::new("v".into(), ["a", "d", "e", "z"]);
+        let out = search_sorted(&edges, &values, SearchSortedSide::Left, false)?;
+        assert_eq!(Vec::from(&out), &[Some(0), Some(1), Some(2), Some(3)]);
+
+        // Descending input searches from the other end.
+        let edges = Series::new("edges".into(), [30i64, 20, 10]);
+        let values = Series::new("v".into(), [25i64]);
+        let out = search_sorted(&edges, &values, SearchSortedSide::Left, true)?;
+        assert_eq!(Vec::from(&out), &[Some(1)]);
+
+        let values = Series::new("v".into(), [25i32]);
+        assert!(search_sorted(&edges, &values, SearchSortedSide::Left, false).is_err());
+        Ok(())
+    }
+}
@@ -95,3 +95,40 @@ fn sort_columns(mut columns: Vec<Column>) -> Vec<Column> {
     columns.sort_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
     columns
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dummy_values(df: &DataFrame, name: &str) -> Vec<Option<i32>> {
+        let s = df.column(name).unwrap().cast(&DataType::Int32).unwrap();
+        Vec::from(s.i32().unwrap())
+    }
+
+    #[test]
+    fn test_to_dummies_nulls() -> PolarsResult<()> {
+        let s = Series::new("x".into(), &[Some("b"), None, Some("a"), Some("b")]);
+
+        let out = s.to_dummies(None, false, false)?;
+        assert_eq!(out.get_column_names_str(), &["x_a", "x_b", "x_null"]);
+        assert_eq!(
+            dummy_values(&out, "x_a"),
+            &[Some(0), Some(0), Some(1), Some(0)]
+        );
+        assert_eq!(
+            dummy_values(&out, "x_b"),
+            &[Some(1), Some(0), Some(0), Some(1)]
+        );
+        assert_eq!(
+            dummy_values(&out, "x_null"),
+            &[Some(0), Some(1), Some(0), Some(0)]
+        );
+
+        // without a null column the null row is all zeros
+        let out = s.to_dummies(Some(":"), false, true)?;
+        assert_eq!(out.get_column_names_str(), &["x:a", "x:b"]);
+        assert_eq!(dummy_values(&out, "x:a")[1], Some(0));
+        assert_eq!(dummy_values(&out, "x:b")[1], Some(0));
+        Ok(())
+    }
+}
@@ -26,6 +26,9 @@ where
 }
 
 /// Returns a count of the unique values in the order of appearance.
+///
+/// The counts are aligned with [`Series::unique_stable`], so the two can be zipped together.
+/// Categoricals and enums are counted by their codes.
 pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
     if s.dtype().to_physical().is_primitive_numeric() {
         let s_physical = s.to_physical_repr();
@@ -39,6 +42,9 @@ pub fn unique_counts(s: &Series) -> PolarsResult<Series> {
             DataType::String => {
                 Ok(unique_counts_helper(s.str().unwrap().into_iter()).into_series())
             },
+            DataType::Boolean => {
+                Ok(unique_counts_helper(s.bool().unwrap().into_iter()).into_series())
+            },
             DataType::Null => {
                 let ca = if s.is_empty() {
                     IdxCa::new(s.name().clone(), [] as [IdxSize; 0])
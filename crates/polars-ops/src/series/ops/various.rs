@@ -1,9 +1,10 @@
-use num_traits::Bounded;
+use num_traits::{Bounded, NumCast};
 #[cfg(feature = "dtype-struct")]
 use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca;
 use polars_core::prelude::arity::unary_elementwise_values;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
+use polars_core::utils::NoNull;
 use polars_core::with_match_physical_numeric_polars_type;
 #[cfg(feature = "hash")]
 use polars_utils::aliases::PlSeedableRandomStateQuality;
@@ -14,6 +15,13 @@ use crate::series::ops::SeriesSealed;
 pub trait SeriesMethods: SeriesSealed {
     /// Create a [`DataFrame`] with the unique `values` of this [`Series`] and a column `"counts"`
     /// with dtype [`IdxType`]
+    ///
+    /// Nulls are counted as their own value. If `sort` is set the output is ordered by descending
+    /// count, with ties broken by ascending value and nulls last. Values that can't be sorted,
+    /// like objects, keep ties in an unspecified order.
+    ///
+    /// Small integers, categoricals and enums are counted in a table indexed by their physical
+    /// value instead of being hashed.
     fn value_counts(
         &self,
         sort: bool,
@@ -27,12 +35,18 @@ pub trait SeriesMethods: SeriesSealed {
             Duplicate: "using `value_counts` on a column/series named '{}' would lead to duplicate \
             column names; change `name` to fix", name,
         );
-        // we need to sort here as well in case of `maintain_order` because duplicates behavior is undefined
-        let groups = s.group_tuples(parallel, sort)?;
-        let values = unsafe { s.agg_first(&groups) }
-            .with_name(s.name().clone())
-            .into();
-        let counts = groups.group_count().with_name(name.clone());
+        let (values, counts) = match value_counts_dense(s)? {
+            Some(out) => out,
+            None => {
+                // we need to sort here as well in case of `maintain_order` because duplicates
+                // behavior is undefined
+                let groups = s.group_tuples(parallel, sort)?;
+                let values = unsafe { s.agg_first(&groups) };
+                (values, groups.group_count())
+            },
+        };
+        let values = values.with_name(s.name().clone()).into();
+        let counts = counts.with_name(name.clone());
 
         let counts = if normalize {
             let len = s.len() as f64;
@@ -47,12 +61,17 @@ pub trait SeriesMethods: SeriesSealed {
         let cols = vec![values, counts];
         let df = unsafe { DataFrame::new_no_checks(height, cols) };
         if sort {
-            df.sort(
-                [name],
-                SortMultipleOptions::default()
-                    .with_order_descending(true)
-                    .with_multithreaded(parallel),
-            )
+            let options = SortMultipleOptions::default()
+                .with_nulls_last(true)
+                .with_multithreaded(parallel);
+            if s.dtype().contains_objects() {
+                df.sort([name], options.with_order_descending(true))
+            } else {
+                df.sort(
+                    [name, s.name().clone()],
+                    options.with_order_descending_multi([true, false]),
+                )
+            }
         } else {
             Ok(df)
         }
@@ -214,4 +233,191 @@ fn is_sorted_ca_num<T: PolarsNumericType>(ca: &ChunkedArray<T>, options: SortOpt
     }
 }
 
+/// Count the values of `s` in a table indexed by their physical value, for dtypes with few
+/// possible values. Returns `None` if `s` has another dtype or the table would be large compared
+/// to the length of `s`.
+fn value_counts_dense(s: &Series) -> PolarsResult<Option<(Series, IdxCa)>> {
+    let table_size = match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
+            mapping.num_cats_upper_bound()
+        },
+        DataType::UInt8 | DataType::Int8 => 1 << 8,
+        DataType::UInt16 | DataType::Int16 => 1 << 16,
+        _ => return Ok(None),
+    };
+    if table_size > s.len().max(1 << 8) {
+        return Ok(None);
+    }
+
+    let phys = s.to_physical_repr();
+    let (values, counts) = match phys.dtype() {
+        DataType::UInt8 => dense_counts(phys.u8()?, table_size),
+        DataType::UInt16 => dense_counts(phys.u16()?, table_size),
+        DataType::UInt32 => dense_counts(phys.u32()?, table_size),
+        DataType::Int8 => dense_counts(phys.i8()?, table_size),
+        DataType::Int16 => dense_counts(phys.i16()?, table_size),
+        dt => unreachable!("unexpected physical dtype {dt:?}"),
+    };
+    let values = unsafe { values.from_physical_unchecked(s.dtype())? };
+    Ok(Some((values, counts)))
+}
+
+/// Count the values of `ca`, which all fall in `table_size` slots starting at the minimum of
+/// `T`. The values are returned in ascending order followed by null.
+fn dense_counts<T>(ca: &ChunkedArray<T>, table_size: usize) -> (Series, IdxCa)
+where
+    T: PolarsIntegerType,
+    ChunkedArray<T>: IntoSeries,
+{
+    let offset = <i64 as NumCast>::from(<T::Native as Bounded>::min_value()).unwrap();
+    let mut table = vec![0 as IdxSize; table_size];
+    for arr in ca.downcast_iter() {
+        for v in arr.non_null_values_iter() {
+            let v = <i64 as NumCast>::from(v).unwrap();
+            table[(v - offset) as usize] += 1;
+        }
+    }
+
+    let null_count = ca.null_count() as IdxSize;
+    let slots = table.iter().enumerate().filter(|(_, count)| **count > 0);
+    let values = slots
+        .clone()
+        .map(|(i, _)| <T::Native as NumCast>::from(i as i64 + offset))
+        .chain((null_count > 0).then_some(None));
+    let counts = slots
+        .map(|(_, count)| *count)
+        .chain((null_count > 0).then_some(null_count));
+
+    let values: ChunkedArray<T> = ChunkedArray::from_iter_options(ca.name().clone(), values);
+    let counts: NoNull<IdxCa> = counts.collect();
+    (values.into_series(), counts.into_inner())
+}
+
 impl SeriesMethods for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_value_counts() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [None, Some("c"), Some("b"), Some("c"), None, Some("a")],
+        );
+
+        let out = s.value_counts(true, false, "count".into(), false)?;
+        // Ties are ordered by value and nulls are a value of their own that sorts last.
+        assert_eq!(
+            Vec::from(out.column("a")?.str()?),
+            &[Some("c"), None, Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(out.column("count")?.idx()?),
+            &[Some(2), Some(2), Some(1), Some(1)]
+        );
+
+        let out = s.value_counts(true, false, "share".into(), true)?;
+        assert_eq!(out.column("share")?.f64()?.get(0), Some(2.0 / 6.0));
+
+        assert!(s.value_counts(false, false, "a".into(), false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_counts_small_integers() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [Some(-3i8), Some(5), None, Some(-3), Some(5), Some(-128)],
+        );
+        // Counted in a table, which gives the same result as hashing for other integers.
+        for s in [s.clone(), s.cast(&DataType::Int32)?] {
+            let out = s.value_counts(true, false, "count".into(), false)?;
+            assert_eq!(
+                Vec::from(out.column("a")?.cast(&DataType::Int32)?.i32()?),
+                &[Some(-3), Some(5), Some(-128), None]
+            );
+            assert_eq!(
+                Vec::from(out.column("count")?.idx()?),
+                &[Some(2), Some(2), Some(1), Some(1)]
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_value_counts_enum() -> PolarsResult<()> {
+        let dtype =
+            DataType::from_frozen_categories(FrozenCategories::new(["x", "y", "z"]).unwrap());
+        let s = Series::new("a".into(), [Some("z"), None, Some("x"), Some("z")]).cast(&dtype)?;
+
+        let out = s.value_counts(true, false, "count".into(), false)?;
+        assert_eq!(out.column("a")?.dtype(), &dtype);
+        assert_eq!(
+            Vec::from(out.column("a")?.cast(&DataType::String)?.str()?),
+            &[Some("z"), Some("x"), None]
+        );
+        assert_eq!(
+            Vec::from(out.column("count")?.idx()?),
+            &[Some(2), Some(1), Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_counts_nested() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [
+                Series::new("".into(), [2]),
+                Series::new("".into(), [1, 2]),
+                Series::new("".into(), [2]),
+                Series::new("".into(), [0]),
+                Series::new("".into(), [1, 2]),
+            ],
+        );
+
+        // Lists are ordered through their row encoding, so ties are still broken by value.
+        let out = s.value_counts(true, false, "count".into(), false)?;
+        let values = out.column("a")?.list()?;
+        let first = |i: usize| values.get_as_series(i).unwrap().i32().unwrap().get(0);
+        assert_eq!([first(0), first(1), first(2)], [Some(1), Some(2), Some(0)]);
+        assert_eq!(
+            Vec::from(out.column("count")?.idx()?),
+            &[Some(2), Some(2), Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "unique_counts")]
+    fn test_unique_counts_aligned_with_unique() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [3, 1, 3, 2, 1, 3]);
+        let unique = s.unique_stable()?;
+        let counts = crate::prelude::unique_counts(&s)?;
+        assert_eq!(Vec::from(unique.i32()?), &[Some(3), Some(1), Some(2)]);
+        assert_eq!(Vec::from(counts.idx()?), &[Some(3), Some(2), Some(1)]);
+
+        let s = Series::new("a".into(), [Some(true), None, Some(false), None, None]);
+        let unique = s.unique_stable()?;
+        let counts = crate::prelude::unique_counts(&s)?;
+        assert_eq!(Vec::from(unique.bool()?), &[Some(true), None, Some(false)]);
+        assert_eq!(Vec::from(counts.idx()?), &[Some(1), Some(3), Some(1)]);
+
+        #[cfg(feature = "dtype-categorical")]
+        {
+            let s = Series::new("a".into(), ["b", "a", "b", "c"])
+                .cast(&DataType::from_categories(Categories::global()))?;
+            let unique = s.unique_stable()?;
+            let counts = crate::prelude::unique_counts(&s)?;
+            assert_eq!(
+                Vec::from(unique.cast(&DataType::String)?.str()?),
+                &[Some("b"), Some("a"), Some("c")]
+            );
+            assert_eq!(Vec::from(counts.idx()?), &[Some(2), Some(1), Some(1)]);
+        }
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use expr_expansion::rewrite_projections;
 use hive::hive_partitions_from_paths;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::config::verbose;
+use polars_utils::format_pl_smallstr;
 use polars_utils::plpath::PlPath;
 use polars_utils::unique_id::UniqueId;
 
@@ -1184,10 +1185,11 @@ fn resolve_group_by(
 
     // Add aggregation column(s)
     let aggs = rewrite_projections(aggs, &key_names, input_schema, opt_flags)?;
-    let aggs = to_expr_irs(
+    let mut aggs = to_expr_irs(
         aggs,
         &mut ExprToIRContext::new_with_opt_eager(expr_arena, input_schema, opt_flags),
     )?;
+    suffix_duplicate_agg_names(&mut aggs, expr_arena);
     utils::validate_expressions(&keys, expr_arena, input_schema, "group by")?;
     utils::validate_expressions(&aggs, expr_arena, input_schema, "group by")?;
 
@@ -1224,6 +1226,87 @@ fn resolve_group_by(
 
     Ok((keys, aggs, Arc::new(output_schema)))
 }
+
+/// Give aggregations that share an output name and have no explicit alias the name of their
+/// aggregation as suffix, e.g. `col("x").sum()` and `col("x").mean()` become `x_sum` and `x_mean`.
+fn suffix_duplicate_agg_names(aggs: &mut [ExprIR], expr_arena: &Arena<AExpr>) {
+    let mut counts = PlHashMap::with_capacity(aggs.len());
+    for agg in aggs.iter() {
+        *counts.entry(agg.output_name().clone()).or_insert(0usize) += 1;
+    }
+    if counts.len() == aggs.len() {
+        return;
+    }
+
+    for agg in aggs.iter_mut() {
+        let OutputName::ColumnLhs(name) = agg.output_name_inner() else {
+            continue;
+        };
+        if counts[name] < 2 {
+            continue;
+        }
+        let AExpr::Agg(agg_expr) = expr_arena.get(agg.node()) else {
+            continue;
+        };
+        let name = format_pl_smallstr!("{name}_{}", agg_name_suffix(agg_expr, expr_arena));
+        *agg = agg.with_alias(name);
+    }
+}
+
+/// The name of the aggregation, in the form of the method that creates it.
+fn agg_name_suffix(agg_expr: &IRAggExpr, expr_arena: &Arena<AExpr>) -> PlSmallStr {
+    use IRAggExpr::*;
+    let suffix = match agg_expr {
+        Min {
+            propagate_nans: false,
+            ..
+        } => "min",
+        Min {
+            propagate_nans: true,
+            ..
+        } => "nan_min",
+        Max {
+            propagate_nans: false,
+            ..
+        } => "max",
+        Max {
+            propagate_nans: true,
+            ..
+        } => "nan_max",
+        Median(_) => "median",
+        NUnique(_) => "n_unique",
+        First(_) => "first",
+        Last(_) => "last",
+        Item(_) => "item",
+        Mean(_) => "mean",
+        Implode(_) => "implode",
+        Quantile { quantile, .. } => {
+            // Keep quantiles with a different literal `quantile` apart.
+            let quantile = match expr_arena.get(*quantile) {
+                AExpr::Literal(lv) => lv.to_any_value().and_then(|av| av.extract::<f64>()),
+                _ => None,
+            };
+            return match quantile {
+                Some(quantile) => format_pl_smallstr!("quantile_{quantile}"),
+                None => PlSmallStr::from_static("quantile"),
+            };
+        },
+        Sum(_) => "sum",
+        Count {
+            include_nulls: false,
+            ..
+        } => "count",
+        Count {
+            include_nulls: true,
+            ..
+        } => "len",
+        Std(..) => "std",
+        Var(..) => "var",
+        AggGroups(_) => "agg_groups",
+    };
+    PlSmallStr::from_static(suffix)
+}
+
 fn stats_helper<F, E>(condition: F, expr: E, schema: &Schema) -> Vec<Expr>
 where
     F: Fn(&DataType) -> bool,
@@ -212,7 +212,11 @@ pub(super) fn process_binary(
             (Duration(_), Duration(_)) => return Ok(None),
             (Duration(_), r) if r.is_primitive_numeric() => return Ok(None),
             (String, a) | (a, String) if a.is_primitive_numeric() => {
-                polars_bail!(InvalidOperation: "arithmetic on string and numeric not allowed, try an explicit cast first")
+                polars_bail!(
+                    InvalidOperation:
+                    "arithmetic on string and numeric not allowed, try an explicit cast first \
+                    (got {} {} {})", type_left, op, type_right
+                )
             },
             (Datetime(_, _), _)
             | (_, Datetime(_, _))
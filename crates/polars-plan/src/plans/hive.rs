@@ -201,6 +201,7 @@ pub fn hive_partitions_from_paths(
     let mut buffers = polars_io::csv::read::buffer::init_buffers(
         &(0..hive_schema.len()).collect::<Vec<_>>(),
         paths.len(),
+        0,
         hive_schema.as_ref(),
         None,
         polars_io::prelude::CsvEncoding::Utf8,
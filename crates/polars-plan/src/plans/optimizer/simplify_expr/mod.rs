@@ -134,6 +134,26 @@ macro_rules! eval_binary_cmp_same_type {
 pub struct SimplifyBooleanRule {}
 
 impl OptimizationRule for SimplifyBooleanRule {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        match lp_arena.get(node) {
+            // filter(true) => input
+            IR::Filter { input, predicate }
+                if matches!(
+                    expr_arena.get(predicate.node()),
+                    AExpr::Literal(lv) if lv.bool() == Some(true)
+                ) =>
+            {
+                Ok(Some(lp_arena.get(*input).clone()))
+            },
+            _ => Ok(None),
+        }
+    }
+
     fn optimize_expr(
         &mut self,
         expr_arena: &mut Arena<AExpr>,
@@ -670,6 +690,23 @@ impl OptimizationRule for SimplifyExprRule {
 
                 None
             },
+            // cast(cast(x, dtype), dtype) => cast(x, dtype)
+            AExpr::Cast {
+                expr: input,
+                dtype,
+                options,
+            } => match expr_arena.get(*input) {
+                AExpr::Cast {
+                    expr: inner,
+                    dtype: inner_dtype,
+                    options: inner_options,
+                } if inner_dtype == dtype && inner_options == options => Some(AExpr::Cast {
+                    expr: *inner,
+                    dtype: dtype.clone(),
+                    options: *options,
+                }),
+                _ => None,
+            },
             AExpr::Function {
                 input,
                 function,
@@ -31,13 +31,13 @@ fn cast_and_apply<
 }
 
 pub trait DatetimeMethods: AsDatetime {
-    /// Extract month from underlying NaiveDateTime representation.
+    /// Extract year from underlying NaiveDateTime representation.
     /// Returns the year number in the calendar date.
     fn year(&self) -> Int32Chunked {
         cast_and_apply(self.as_datetime(), temporal::year)
     }
 
-    /// Extract year from underlying NaiveDate representation.
+    /// Extract year from underlying NaiveDateTime representation.
     /// Returns whether the year is a leap year.
     fn is_leap_year(&self) -> BooleanChunked {
         let ca = self.as_datetime();
@@ -60,6 +60,8 @@ pub trait DatetimeMethods: AsDatetime {
         ca_local.physical().apply_kernel_cast::<BooleanType>(&f)
     }
 
+    /// Returns the ISO 8601 week-numbering year.
+    /// This year number might not match the calendar year number.
     fn iso_year(&self) -> Int32Chunked {
         let ca = self.as_datetime();
         let f = match ca.time_unit() {
@@ -157,7 +159,7 @@ pub trait DatetimeMethods: AsDatetime {
         cast_and_apply(self.as_datetime(), temporal::second)
     }
 
-    /// Extract second from underlying NaiveDateTime representation.
+    /// Extract nanosecond from underlying NaiveDateTime representation.
     /// Returns the number of nanoseconds since the whole non-leap second.
     /// The range from 1,000,000,000 to 1,999,999,999 represents the leap second.
     fn nanosecond(&self) -> Int32Chunked {
@@ -325,4 +327,33 @@ mod test {
             dt.physical().cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn test_datetime_parts() {
+        let datetimes = [
+            Some("2021-01-01 13:45:30"),
+            None,
+            Some("2024-12-30 00:00:59"),
+        ]
+        .map(|opt| opt.map(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()));
+        let dt = DatetimeChunked::from_naive_datetime_options(
+            "name".into(),
+            datetimes,
+            TimeUnit::Milliseconds,
+        );
+
+        assert_eq!(Vec::from(&dt.year()), &[Some(2021), None, Some(2024)]);
+        assert_eq!(Vec::from(&dt.month()), &[Some(1), None, Some(12)]);
+        assert_eq!(Vec::from(&dt.day()), &[Some(1), None, Some(30)]);
+        assert_eq!(Vec::from(&dt.hour()), &[Some(13), None, Some(0)]);
+        assert_eq!(Vec::from(&dt.minute()), &[Some(45), None, Some(0)]);
+        assert_eq!(Vec::from(&dt.second()), &[Some(30), None, Some(59)]);
+        assert_eq!(Vec::from(&dt.ordinal()), &[Some(1), None, Some(365)]);
+
+        // 2021-01-01 is a Friday in the last ISO week of 2020, 2024-12-30 is a Monday
+        // in the first ISO week of 2025.
+        assert_eq!(Vec::from(&dt.weekday()), &[Some(5), None, Some(1)]);
+        assert_eq!(Vec::from(&dt.week()), &[Some(53), None, Some(1)]);
+        assert_eq!(Vec::from(&dt.iso_year()), &[Some(2020), None, Some(2025)]);
+    }
 }
@@ -12,6 +12,9 @@ pub fn in_nanoseconds_window(ndt: &NaiveDateTime) -> bool {
 }
 
 /// Create a [`DatetimeChunked`] from a given `start` and `end` date and a given `interval`.
+///
+/// `closed` determines whether `start` and `end` are included. A `start` after `end` or an
+/// `interval` that is zero or negative is an error.
 pub fn date_range(
     name: PlSmallStr,
     start: NaiveDateTime,
@@ -94,6 +97,8 @@ pub fn time_range_impl(
 }
 
 /// vector of i64 representing temporal values
+///
+/// A `start` after `end` is an error, so the eager functions and the range expressions agree.
 pub(crate) fn datetime_range_i64(
     start: i64,
     end: i64,
@@ -102,13 +107,14 @@ pub(crate) fn datetime_range_i64(
     time_unit: TimeUnit,
     time_zone: Option<&Tz>,
 ) -> PolarsResult<Vec<i64>> {
-    if start > end {
-        return Ok(Vec::new());
-    }
     polars_ensure!(
         !interval.negative && !interval.is_zero(),
         ComputeError: "`interval` must be positive"
     );
+    polars_ensure!(
+        start <= end,
+        ComputeError: "`start` must be before or equal to `end`"
+    );
 
     let duration = match time_unit {
         TimeUnit::Nanoseconds => interval.duration_ns(),
@@ -86,7 +86,8 @@ impl PolarsRound for DatetimeChunked {
             every,
             |opt_timestamp, opt_every| match (opt_timestamp, opt_every) {
                 (Some(timestamp), Some(every)) => {
-                    let every = *duration_cache.get_or_insert_with(every, Duration::parse);
+                    let every =
+                        *duration_cache.try_get_or_insert_with(every, Duration::try_parse)?;
 
                     if every.negative {
                         polars_bail!(ComputeError: "cannot round a Datetime to a negative duration")
@@ -136,7 +137,8 @@ impl PolarsRound for DateChunked {
                         LruCache::with_capacity((every.len() as f64).sqrt() as usize);
                     match (opt_t, opt_every) {
                         (Some(t), Some(every)) => {
-                            let every = *duration_cache.get_or_insert_with(every, Duration::parse);
+                            let every = *duration_cache
+                                .try_get_or_insert_with(every, Duration::try_parse)?;
 
                             if every.negative {
                                 polars_bail!(ComputeError: "cannot round a Date to a negative duration")
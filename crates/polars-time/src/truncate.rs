@@ -33,7 +33,7 @@ impl PolarsTruncate for DatetimeChunked {
         // Let's check if we can use a fastpath...
         if every.len() == 1 {
             if let Some(every) = every.get(0) {
-                let every_parsed = Duration::parse(every);
+                let every_parsed = Duration::try_parse(every)?;
                 if every_parsed.negative {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
@@ -89,7 +89,8 @@ impl PolarsTruncate for DatetimeChunked {
             every,
             |opt_timestamp, opt_every| match (opt_timestamp, opt_every) {
                 (Some(timestamp), Some(every)) => {
-                    let every = *duration_cache.get_or_insert_with(every, Duration::parse);
+                    let every =
+                        *duration_cache.try_get_or_insert_with(every, Duration::try_parse)?;
 
                     if every.negative {
                         polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
@@ -118,7 +119,7 @@ impl PolarsTruncate for DateChunked {
         let out = match every.len() {
             1 => {
                 if let Some(every) = every.get(0) {
-                    let every = Duration::parse(every);
+                    let every = Duration::try_parse(every)?;
                     if every.negative {
                         polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
                     }
@@ -137,7 +138,8 @@ impl PolarsTruncate for DateChunked {
                     LruCache::with_capacity((every.len() as f64).sqrt() as usize);
                 match (opt_t, opt_every) {
                     (Some(t), Some(every)) => {
-                        let every = *duration_cache.get_or_insert_with(every, Duration::parse);
+                        let every =
+                            *duration_cache.try_get_or_insert_with(every, Duration::try_parse)?;
 
                         if every.negative {
                             polars_bail!(ComputeError: "cannot truncate a Date to a negative duration")
@@ -156,3 +158,90 @@ impl PolarsTruncate for DateChunked {
         Ok(out?.into_date())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+    use crate::PolarsRound;
+
+    fn ms(s: &str) -> i64 {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    }
+
+    fn datetimes(values: &[&str]) -> DatetimeChunked {
+        Int64Chunked::from_iter_values(PlSmallStr::from_static("dt"), values.iter().map(|s| ms(s)))
+            .into_datetime(TimeUnit::Milliseconds, None)
+    }
+
+    fn every(every: &str) -> StringChunked {
+        StringChunked::from_slice(PlSmallStr::EMPTY, &[every])
+    }
+
+    #[test]
+    fn test_truncate_calendar() -> PolarsResult<()> {
+        let ca = datetimes(&[
+            "2024-01-31 23:59:59",
+            "2024-02-29 12:00:00",
+            "2024-01-07 08:00:00",
+            "1969-12-31 23:30:00",
+        ]);
+
+        let out = ca.truncate(None, &every("1mo"))?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ms("2024-01-01 00:00:00")),
+                Some(ms("2024-02-01 00:00:00")),
+                Some(ms("2024-01-01 00:00:00")),
+                Some(ms("1969-12-01 00:00:00")),
+            ]
+        );
+
+        // Weeks start on Monday; 2024-01-07 is a Sunday.
+        let out = ca.truncate(None, &every("1w"))?;
+        assert_eq!(out.physical().get(2), Some(ms("2024-01-01 00:00:00")));
+
+        // Pre-epoch timestamps are floored, not truncated towards zero.
+        let out = ca.truncate(None, &every("1h"))?;
+        assert_eq!(out.physical().get(3), Some(ms("1969-12-31 23:00:00")));
+        let out = ca.truncate(None, &every("1d"))?;
+        assert_eq!(out.physical().get(3), Some(ms("1969-12-31 00:00:00")));
+
+        let out = ca.round(&every("1h"), None)?;
+        assert_eq!(
+            Vec::from(out.physical()),
+            &[
+                Some(ms("2024-02-01 00:00:00")),
+                Some(ms("2024-02-29 12:00:00")),
+                Some(ms("2024-01-07 08:00:00")),
+                Some(ms("1970-01-01 00:00:00")),
+            ]
+        );
+
+        assert!(ca.truncate(None, &every("-1d")).is_err());
+        assert!(ca.truncate(None, &every("1x")).is_err());
+        assert!(ca.round(&every("1x"), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_truncate_across_dst() -> PolarsResult<()> {
+        let tz = chrono_tz::Europe::Amsterdam;
+        // Noon local time on the day the clocks go forward.
+        let ca =
+            Int64Chunked::from_slice(PlSmallStr::from_static("dt"), &[ms("2021-03-28 10:00:00")])
+                .into_datetime(TimeUnit::Milliseconds, Some(TimeZone::from_chrono(&tz)));
+
+        // Local midnight was still in winter time (UTC+1).
+        let out = ca.truncate(Some(&tz), &every("1d"))?;
+        assert_eq!(out.physical().get(0), Some(ms("2021-03-27 23:00:00")));
+        assert_eq!(out.time_zone(), ca.time_zone());
+        Ok(())
+    }
+}
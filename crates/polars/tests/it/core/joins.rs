@@ -682,3 +682,228 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "semi_anti_join")]
+fn test_semi_anti_join_multiple_columns() -> PolarsResult<()> {
+    let (df_a, df_b) = get_dfs();
+
+    // The right side has two matches for ("1", "c"), this must not duplicate left rows.
+    let out = df_a.join(
+        &df_b,
+        ["a", "b"],
+        ["foo", "bar"],
+        JoinType::Semi.into(),
+        None,
+    )?;
+    assert_eq!(out.get_column_names_str(), &["a", "b", "c"]);
+    assert_eq!(
+        Vec::from(out.column("c")?.i32()?),
+        &[Some(0), Some(2), Some(3)]
+    );
+
+    let out = df_a.join(
+        &df_b,
+        ["a", "b"],
+        ["foo", "bar"],
+        JoinType::Anti.into(),
+        None,
+    )?;
+    assert_eq!(out.get_column_names_str(), &["a", "b", "c"]);
+    assert_eq!(Vec::from(out.column("c")?.i32()?), &[Some(1)]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "cross_join")]
+fn test_cross_join() -> PolarsResult<()> {
+    let left = df! {
+        "a" => [1, 2],
+        "b" => ["x", "y"]
+    }?;
+    let right = df! {
+        "a" => [10, 20, 30]
+    }?;
+
+    let out = left.cross_join(&right, None, None, MaintainOrderJoin::Left)?;
+    assert_eq!(out.get_column_names_str(), &["a", "b", "a_right"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(1), Some(1), Some(2), Some(2), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("a_right")?.i32()?),
+        &[Some(10), Some(20), Some(30), Some(10), Some(20), Some(30)]
+    );
+
+    let out = left.cross_join(&right.clear(), None, None, MaintainOrderJoin::Left)?;
+    assert_eq!(out.height(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(all(feature = "cross_join", not(feature = "bigidx")))]
+fn test_cross_join_height_overflow() -> PolarsResult<()> {
+    // 70_000 * 70_000 rows doesn't fit in a u32 index, this must error before allocating.
+    let df = df! {
+        "a" => vec![0i32; 70_000]
+    }?;
+    let out = df.cross_join(&df, None, None, MaintainOrderJoin::Left);
+    assert!(matches!(out, Err(PolarsError::ComputeError(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_suffix_collision() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [1, 2],
+        "b" => [3, 4]
+    }?;
+
+    // A self-join where every column collides.
+    let out = df.join(
+        &df,
+        ["a"],
+        ["a"],
+        JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::KeepColumns),
+        None,
+    )?;
+    assert_eq!(
+        out.get_column_names_str(),
+        &["a", "b", "a_right", "b_right"]
+    );
+
+    let out = df.join(
+        &df,
+        ["a"],
+        ["a"],
+        JoinArgs::new(JoinType::Inner).with_suffix(Some("_r".into())),
+        None,
+    )?;
+    assert_eq!(out.get_column_names_str(), &["a", "b", "b_r"]);
+
+    // `b_right` already exists on the left, so suffixing `b` must not silently
+    // produce a duplicate column.
+    let left = df.inner_join(&df, ["a"], ["a"])?;
+    assert_eq!(left.get_column_names_str(), &["a", "b", "b_right"]);
+    let out = left.inner_join(&df, ["a"], ["a"]);
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+
+    let out = left.join(
+        &df,
+        ["a"],
+        ["a"],
+        JoinArgs::new(JoinType::Inner).with_suffix(Some("_2".into())),
+        None,
+    )?;
+    assert_eq!(out.get_column_names_str(), &["a", "b", "b_right", "b_2"]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_full_join_composite_keys_with_nulls() -> PolarsResult<()> {
+    let df_left = df! {
+        "a" => [Some(1), Some(1), None, Some(2)],
+        "b" => [Some("x"), Some("y"), Some("x"), None],
+        "l" => [1, 2, 3, 4]
+    }?;
+    let df_right = df! {
+        "a" => [Some(1), None, Some(3), Some(2)],
+        "b" => [Some("x"), Some("x"), Some("z"), None],
+        "r" => [10, 20, 30, 40]
+    }?;
+    let sort_options = SortMultipleOptions::default().with_nulls_last(true);
+
+    // Null keys don't match by default, the key columns of a row must all come from the same side.
+    let out = df_left
+        .join(
+            &df_right,
+            ["a", "b"],
+            ["a", "b"],
+            JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+            None,
+        )?
+        .sort(["l", "r"], sort_options.clone())?;
+    assert_eq!(out.get_column_names_str(), &["a", "b", "l", "r"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(1), None, Some(2), None, Some(3), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.str()?),
+        &[
+            Some("x"),
+            Some("y"),
+            Some("x"),
+            None,
+            Some("x"),
+            Some("z"),
+            None
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("r")?.i32()?),
+        &[Some(10), None, None, None, Some(20), Some(30), Some(40)]
+    );
+
+    let out = df_left
+        .join(
+            &df_right,
+            ["a", "b"],
+            ["a", "b"],
+            JoinArgs::new(JoinType::Full)
+                .with_coalesce(JoinCoalesce::CoalesceColumns)
+                .with_nulls_equal(true),
+            None,
+        )?
+        .sort(["l", "r"], sort_options)?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(1), None, Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.str()?),
+        &[Some("x"), Some("y"), Some("x"), None, Some("z")]
+    );
+    assert_eq!(
+        Vec::from(out.column("r")?.i32()?),
+        &[Some(10), None, Some(20), Some(40), Some(30)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-u8")]
+fn test_join_unsigned_keys() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1u32, 2, 3, 4],
+        "flag" => [0u8, 1, 1, 0],
+    ]?;
+    let right = df![
+        "id" => [4u32, 2, 5],
+        "flag" => [0u8, 1, 0],
+        "label" => ["d", "b", "e"],
+    ]?;
+
+    let out = left.join(
+        &right,
+        ["id", "flag"],
+        ["id", "flag"],
+        JoinArgs::new(JoinType::Inner),
+        None,
+    )?;
+    let out = out.sort(["id"], Default::default())?;
+    assert_eq!(out.column("id")?.dtype(), &DataType::UInt32);
+    assert_eq!(out.column("flag")?.dtype(), &DataType::UInt8);
+    assert_eq!(Vec::from(out.column("id")?.u32()?), &[Some(2), Some(4)]);
+    assert_eq!(
+        Vec::from(out.column("label")?.str()?),
+        &[Some("b"), Some("d")]
+    );
+    Ok(())
+}
@@ -14,3 +14,169 @@ fn test_to_list_logical() -> PolarsResult<()> {
     assert_eq!(expl.dtype(), &DataType::Date);
     Ok(())
 }
+
+#[test]
+fn test_list_lengths_get_first_last() -> PolarsResult<()> {
+    let s = Series::new(
+        "a".into(),
+        &[
+            Some(Series::new("".into(), &[1i32, 2, 3])),
+            None,
+            Some(Series::new_empty("".into(), &DataType::Int32)),
+            Some(Series::new("".into(), &[4i32])),
+        ],
+    );
+    let ca = s.list()?;
+
+    assert_eq!(
+        Vec::from(&ca.lst_lengths()),
+        &[Some(3), None, Some(0), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(ca.lst_first()?.i32()?),
+        &[Some(1), None, None, Some(4)]
+    );
+    assert_eq!(
+        Vec::from(ca.lst_last()?.i32()?),
+        &[Some(3), None, None, Some(4)]
+    );
+    assert_eq!(
+        Vec::from(ca.lst_get(-2, true)?.i32()?),
+        &[Some(2), None, None, None]
+    );
+    assert!(ca.lst_get(1, false).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_list_contains() -> PolarsResult<()> {
+    let s = Series::new(
+        "a".into(),
+        &[
+            Some(Series::new("".into(), &[Some(1i32), Some(2)])),
+            Some(Series::new("".into(), &[Some(3i32)])),
+            None,
+            Some(Series::new("".into(), &[None, Some(4i32)])),
+        ],
+    );
+    let ca = s.list()?;
+
+    let item = Series::new("".into(), &[2i32]);
+    let out = ca.lst_contains(&item, false)?;
+    assert_eq!(out.name().as_str(), "a");
+    assert_eq!(
+        Vec::from(&out),
+        &[Some(true), Some(false), None, Some(false)]
+    );
+
+    let item = Series::new("".into(), &[1i32, 4, 1, 4]);
+    let out = ca.lst_contains(&item, false)?;
+    assert_eq!(
+        Vec::from(&out),
+        &[Some(true), Some(false), None, Some(true)]
+    );
+
+    let item = Series::new("".into(), &[None::<i32>]);
+    let out = ca.lst_contains(&item, true)?;
+    assert_eq!(
+        Vec::from(&out),
+        &[Some(false), Some(false), None, Some(true)]
+    );
+
+    let s = Series::new(
+        "tags".into(),
+        &[
+            Series::new("".into(), &["a", "urgent"]),
+            Series::new("".into(), &["b"]),
+        ],
+    );
+    let item = Series::new("".into(), &["urgent"]);
+    let out = s.list()?.lst_contains(&item, false)?;
+    assert_eq!(Vec::from(&out), &[Some(true), Some(false)]);
+    Ok(())
+}
+
+#[test]
+fn test_list_join() -> PolarsResult<()> {
+    let s = Series::new(
+        "a".into(),
+        &[
+            Some(Series::new("".into(), &[Some("a"), Some("b")])),
+            Some(Series::new("".into(), &[Some("c"), None, Some("d")])),
+            None,
+            Some(Series::new_empty("".into(), &DataType::String)),
+        ],
+    );
+    let ca = s.list()?;
+    let separator = StringChunked::new("".into(), &[", "]);
+
+    let out = ca.lst_join(&separator, true)?;
+    assert_eq!(
+        Vec::from(&out),
+        &[Some("a, b"), Some("c, d"), None, Some("")]
+    );
+
+    let out = ca.lst_join(&separator, false)?;
+    assert_eq!(Vec::from(&out), &[Some("a, b"), None, None, Some("")]);
+
+    // Sliced lists must respect the list offsets.
+    let out = ca.slice(1, 2).lst_join(&separator, true)?;
+    assert_eq!(Vec::from(&out), &[Some("c, d"), None]);
+    Ok(())
+}
+
+#[test]
+fn test_list_sort_reverse_unique() -> PolarsResult<()> {
+    let s = Series::new(
+        "a".into(),
+        &[
+            Some(Series::new("".into(), &[3i64, 1, 3, 2])),
+            None,
+            Some(Series::new_empty("".into(), &DataType::Int64)),
+            Some(Series::new("".into(), &[5i64, 5])),
+        ],
+    );
+    let ca = s.list()?;
+
+    let check = |out: ListChunked, expected: &[Option<i64>]| -> PolarsResult<()> {
+        assert_eq!(out.len(), 4);
+        assert_eq!(out.null_count(), 1);
+        assert!(out.get_as_series(1).is_none());
+        let exploded = out.explode(false)?;
+        assert_eq!(Vec::from(exploded.i64()?), expected);
+        Ok(())
+    };
+
+    let sorted = ca.lst_sort(SortOptions::default().with_order_descending(true))?;
+    check(
+        sorted,
+        &[
+            Some(3),
+            Some(3),
+            Some(2),
+            Some(1),
+            None,
+            None,
+            Some(5),
+            Some(5),
+        ],
+    )?;
+    check(
+        ca.lst_reverse(),
+        &[
+            Some(2),
+            Some(3),
+            Some(1),
+            Some(3),
+            None,
+            None,
+            Some(5),
+            Some(5),
+        ],
+    )?;
+    check(
+        ca.lst_unique_stable()?,
+        &[Some(3), Some(1), Some(2), None, None, Some(5)],
+    )?;
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use chrono::NaiveDate;
 use polars::prelude::*;
 use polars_lazy::frame::pivot::PivotExpr;
-use polars_ops::pivot::{PivotAgg, pivot, pivot_stable};
+use polars_ops::pivot::{PivotAgg, pivot, pivot_stable, pivot_stable_multiple_aggs};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -271,3 +271,120 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_multiple_aggs() -> PolarsResult<()> {
+    let df = df![
+        "index" => ["A", "A", "B"],
+        "on" => ["x", "y", "x"],
+        "values" => [1, 2, 3],
+    ]?;
+
+    let out = pivot_stable_multiple_aggs(
+        &df,
+        ["on"],
+        Some(["index"]),
+        Some(["values"]),
+        false,
+        vec![
+            (
+                "sum".into(),
+                PivotAgg(Arc::new(PivotExpr::from_expr(col("").sum()))),
+            ),
+            (
+                "count".into(),
+                PivotAgg(Arc::new(PivotExpr::from_expr(col("").count()))),
+            ),
+        ],
+        None,
+    )?;
+    let expected = df![
+        "index" => ["A", "B"],
+        "x_sum" => [1, 3],
+        "y_sum" => [2, 0],
+        "x_count" => [1 as IdxSize, 1],
+        "y_count" => [1 as IdxSize, 0],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_pivot_multiple_aggs_sorted_null_and_numeric_on() -> PolarsResult<()> {
+    let aggs = || {
+        vec![
+            (
+                PlSmallStr::from_static("sum"),
+                PivotAgg(Arc::new(PivotExpr::from_expr(col("").sum()))),
+            ),
+            (
+                PlSmallStr::from_static("count"),
+                PivotAgg(Arc::new(PivotExpr::from_expr(col("").count()))),
+            ),
+        ]
+    };
+    let names = |df: &DataFrame| {
+        df.get_column_names()
+            .into_iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // A null `on` value gets a column of its own; `sort_columns` orders the pivoted columns of
+    // every aggregation by name.
+    let df = df![
+        "index" => ["A", "A", "B", "B"],
+        "on" => [Some("y"), None, Some("x"), Some("y")],
+        "values" => [1, 2, 3, 4],
+    ]?;
+    let out = pivot_stable_multiple_aggs(
+        &df,
+        ["on"],
+        Some(["index"]),
+        Some(["values"]),
+        true,
+        aggs(),
+        None,
+    )?;
+    let expected = df![
+        "index" => ["A", "B"],
+        "null_sum" => [2, 0],
+        "x_sum" => [0, 3],
+        "y_sum" => [1, 4],
+        "null_count" => [1 as IdxSize, 0],
+        "x_count" => [0 as IdxSize, 1],
+        "y_count" => [1 as IdxSize, 1],
+    ]?;
+    assert_eq!(names(&out), names(&expected));
+    assert!(out.equals_missing(&expected));
+
+    // Numeric `on` values are formatted into the column names.
+    let df = df![
+        "index" => ["A", "A", "B", "B"],
+        "on" => [3, 1, 2, 1],
+        "values" => [1, 2, 3, 4],
+    ]?;
+    let out = pivot_stable_multiple_aggs(
+        &df,
+        ["on"],
+        Some(["index"]),
+        Some(["values"]),
+        true,
+        aggs(),
+        Some("/"),
+    )?;
+    let expected = df![
+        "index" => ["A", "B"],
+        "1/sum" => [2, 4],
+        "2/sum" => [0, 3],
+        "3/sum" => [1, 0],
+        "1/count" => [1 as IdxSize, 1],
+        "2/count" => [0 as IdxSize, 1],
+        "3/count" => [1 as IdxSize, 0],
+    ]?;
+    assert_eq!(names(&out), names(&expected));
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
@@ -280,3 +280,41 @@ fn test_rolling_var() {
         "{out:?} is not approximately equal to {exp_res:?}"
     );
 }
+
+#[test]
+fn test_rolling_weights_and_nulls() {
+    let s = Int32Chunked::new("foo".into(), &[1, 2, 3, 4]).into_series();
+    let out = s
+        .rolling_sum(RollingOptionsFixedWindow {
+            window_size: 2,
+            min_periods: 2,
+            weights: Some(vec![1., 2.]),
+            ..Default::default()
+        })
+        .unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(Vec::from(out), &[None, Some(5.0), Some(8.0), Some(11.0)]);
+
+    // Nulls are skipped inside the window, `min_periods` counts valid values.
+    let s =
+        Int32Chunked::new("foo".into(), &[Some(1), None, Some(3), Some(4), Some(5)]).into_series();
+    let options = RollingOptionsFixedWindow {
+        window_size: 3,
+        min_periods: 2,
+        ..Default::default()
+    };
+    let out = s.rolling_sum(options.clone()).unwrap();
+    let out = out.i32().unwrap();
+    assert_eq!(Vec::from(out), &[None, None, Some(4), Some(7), Some(12)]);
+
+    let out = s.rolling_min(options.clone()).unwrap();
+    let out = out.i32().unwrap();
+    assert_eq!(Vec::from(out), &[None, None, Some(1), Some(3), Some(3)]);
+
+    let out = s.rolling_std(options).unwrap();
+    let out = out.f64().unwrap();
+    assert_eq!(out.get(0), None);
+    assert_eq!(out.get(1), None);
+    assert!((out.get(2).unwrap() - 2f64.sqrt()).abs() < 1e-9);
+    assert!((out.get(4).unwrap() - 1.0).abs() < 1e-9);
+}
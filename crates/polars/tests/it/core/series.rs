@@ -41,3 +41,83 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_global_categorical_across_series() -> PolarsResult<()> {
+    let dtype = DataType::from_categories(Categories::global());
+    let a = Series::new("country".into(), ["nl", "be", "nl"]).cast(&dtype)?;
+    let b = Series::new("country".into(), ["de", "be", "nl"]).cast(&dtype)?;
+
+    // Series categorised separately share their codes.
+    let (a_codes, b_codes) = (a.to_physical_repr(), b.to_physical_repr());
+    assert_eq!(a_codes.get(1)?, b_codes.get(1)?);
+    assert_eq!(a_codes.get(0)?, b_codes.get(2)?);
+    assert_eq!(
+        Vec::from(&a.equal(&b)?),
+        &[Some(false), Some(true), Some(true)]
+    );
+
+    let mut c = a.clone();
+    c.append(&b)?;
+    assert_eq!(
+        Vec::from(c.cast(&DataType::String)?.str()?),
+        &[
+            Some("nl"),
+            Some("be"),
+            Some("nl"),
+            Some("de"),
+            Some("be"),
+            Some("nl")
+        ]
+    );
+
+    let df = DataFrame::new(vec![c.into_column()])?;
+    let out = df
+        .group_by_stable(["country"])?
+        .select(["country"])
+        .count()?;
+    assert_eq!(out.column("country")?.dtype(), &dtype);
+    assert_eq!(
+        Vec::from(out.column("country")?.cast(&DataType::String)?.str()?),
+        &[Some("nl"), Some("be"), Some("de")]
+    );
+    assert_eq!(
+        Vec::from(out.column("country_count")?.idx()?),
+        &[Some(3), Some(2), Some(1)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-u8",
+    feature = "dtype-u16",
+    feature = "dtype-i8",
+    feature = "dtype-i16"
+))]
+fn test_small_int_arithmetic_and_sort() -> PolarsResult<()> {
+    let a = Series::new("a".into(), vec![200u8, 1, 50]);
+    assert_eq!(a.dtype(), &DataType::UInt8);
+    let b = Series::new("b".into(), vec![-100i8, 2, 3]);
+    let c = Series::new("c".into(), vec![1u16, 2, 3]);
+    let d = Series::new("d".into(), vec![1u32, 2, 3]);
+    let e = Series::new("e".into(), vec![-1i32, 2, 3]);
+
+    // Mixed signedness widens to a signed type that fits both operands.
+    let out = (&a + &b)?;
+    assert_eq!(out.dtype(), &DataType::Int16);
+    assert_eq!(Vec::from(out.i16()?), &[Some(100), Some(3), Some(53)]);
+    assert_eq!((&a + &c)?.dtype(), &DataType::UInt16);
+    assert_eq!((&d + &e)?.dtype(), &DataType::Int64);
+
+    let sorted = a.sort(SortOptions::default().with_order_descending(true))?;
+    assert_eq!(sorted.dtype(), &DataType::UInt8);
+    assert_eq!(Vec::from(sorted.u8()?), &[Some(200), Some(50), Some(1)]);
+
+    let flags = Series::new("flags".into(), vec![1u8, 0, 1, 1, 0]);
+    let groups = flags.group_tuples(true, false)?;
+    assert_eq!(groups.len(), 2);
+    assert_eq!(flags.n_unique()?, 2);
+    Ok(())
+}
@@ -485,3 +485,35 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_write_and_read_nulls_with_n_rows() -> PolarsResult<()> {
+    use polars::prelude::{IntoSeries, ListChunked, NamedFrom, Series};
+
+    let list = ListChunked::from_iter([
+        Some(Series::new("".into(), [1i64, 2])),
+        None,
+        Some(Series::new("".into(), [3i64])),
+    ])
+    .with_name("list".into());
+    let mut df = df!(
+        "i64" => &[Some(1i64), None, Some(3)],
+        "f64" => &[None, Some(0.2), Some(0.3)],
+        "string" => &[Some("a"), Some("b"), None],
+        "bool" => &[Some(true), None, Some(false)]
+    )?;
+    df.with_column(list.into_series())?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    AvroWriter::new(&mut buf).finish(&mut df)?;
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(&mut buf).finish()?;
+    assert!(df.equals_missing(&read_df));
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(buf).with_n_rows(Some(2)).finish()?;
+    assert!(df.head(Some(2)).equals_missing(&read_df));
+
+    Ok(())
+}
@@ -39,6 +39,76 @@ fn write_csv() {
     assert_eq!("0,22.1\r\n1,19.9\r\n2,7.0\r\n3,2.0\r\n4,3.0\r\n", csv);
 }
 
+#[test]
+fn write_csv_batches_across_chunks() -> PolarsResult<()> {
+    let df = create_df();
+    let expected = "days,temp\n0,22.1\n1,19.9\n2,7.0\n3,2.0\n4,3.0\n";
+
+    // Chunk boundaries at rows 2 and 3 don't line up with the batch size.
+    let mut chunked = df.slice(0, 2);
+    chunked.vstack_mut(&df.slice(2, 1))?;
+    chunked.vstack_mut(&df.slice(3, 2))?;
+    assert_eq!(chunked.first_col_n_chunks(), 3);
+
+    for n_threads in [1, 2] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_batch_size(NonZeroUsize::new(2).unwrap())
+            .n_threads(n_threads)
+            .finish(&mut chunked)?;
+        assert_eq!(std::str::from_utf8(&buf).unwrap(), expected);
+    }
+    // The frame given to the writer is not rechunked.
+    assert_eq!(chunked.first_col_n_chunks(), 3);
+
+    // The batched writer only writes the header once.
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = CsvWriter::new(&mut buf)
+        .with_batch_size(NonZeroUsize::new(2).unwrap())
+        .batched(df.schema())?;
+    writer.write_batch(&df.slice(0, 3))?;
+    writer.write_batch(&df.slice(3, 2))?;
+    writer.finish()?;
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "decompress")]
+fn write_compressed_csv() -> PolarsResult<()> {
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => ["x"; 1000],
+    )?;
+    let read = |bytes: Vec<u8>| {
+        CsvReadOptions::default()
+            .into_reader_with_file_handle(Cursor::new(bytes))
+            .finish()
+    };
+
+    for compression in [CsvCompression::Gzip, CsvCompression::Zstd] {
+        let mut whole: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut whole)
+            .with_compression(compression)
+            .finish(&mut df)?;
+        assert!(read(whole.clone())?.equals(&df), "{compression:?}");
+
+        // All batches are compressed into a single stream, not into a gzip member or zstd frame
+        // per batch.
+        let mut batched: Vec<u8> = Vec::new();
+        let mut writer = CsvWriter::new(&mut batched)
+            .with_compression(compression)
+            .batched(df.schema())?;
+        for i in 0..df.height() {
+            writer.write_batch(&df.slice(i as i64, 1))?;
+        }
+        writer.finish()?;
+        assert!(batched.len() < 2 * whole.len(), "{compression:?}");
+        assert!(read(batched)?.equals(&df), "{compression:?}");
+    }
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "timezones")]
 fn write_dates() {
@@ -534,6 +604,27 @@ fn test_empty_bytes_to_dataframe() {
     assert!(result.is_ok())
 }
 
+#[test]
+fn test_projection_by_column_name() -> PolarsResult<()> {
+    let csv = "a,b,c,d\n1,x,2.5,true\n2,y,3.5,false\n";
+
+    let df = CsvReadOptions::default()
+        .with_columns(Some(["b", "d"].into_iter().map(PlSmallStr::from).collect()))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.get_column_names_str(), &["b", "d"]);
+    assert_eq!(df.dtypes(), &[DataType::String, DataType::Boolean]);
+
+    let err = CsvReadOptions::default()
+        .with_columns(Some(["a", "e"].into_iter().map(PlSmallStr::from).collect()))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::ColumnNotFound(_)));
+    assert!(err.to_string().contains("\"e\""));
+    Ok(())
+}
+
 #[test]
 fn test_carriage_return() {
     let csv = "\"foo\",\"bar\"\r\n\"158252579.00\",\"7.5800\"\r\n\"158252579.00\",\"7.5800\"\r\n";
@@ -570,6 +661,42 @@ fn test_missing_value() {
     assert_eq!(df.column("ham").unwrap().len(), 3)
 }
 
+#[test]
+fn test_schema_overwrite_by_name_parse_errors() -> PolarsResult<()> {
+    let csv = r#"a,b,c
+1,2,x
+3,4,y
+5,oops,z
+"#;
+    let schema_overwrite = Some(Arc::new(Schema::from_iter([Field::new(
+        "b".into(),
+        DataType::Int32,
+    )])));
+
+    // Unparsable values raise an error that points to the offending field and column.
+    let err = CsvReadOptions::default()
+        .with_schema_overwrite(schema_overwrite.clone())
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()
+        .unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("`oops`"), "{msg}");
+    assert!(msg.contains("column 'b'"), "{msg}");
+
+    // Only the named column is overwritten, the others are still inferred.
+    let df = CsvReadOptions::default()
+        .with_schema_overwrite(schema_overwrite)
+        .with_ignore_errors(true)
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Int64, DataType::Int32, DataType::String]
+    );
+    assert_eq!(Vec::from(df.column("b")?.i32()?), &[Some(2), Some(4), None]);
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "temporal")]
 fn test_with_dtype() -> PolarsResult<()> {
@@ -732,6 +859,36 @@ fn test_comment_lines() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_comment_lines_quoted_and_skip_after_header() -> PolarsResult<()> {
+    let csv = r#"a,b
+-,units
+# metadata
+1,"multi
+# not a comment"
+# metadata
+2,"x # y"
+3,z
+"#;
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_skip_rows_after_header(1)
+        .map_parse_options(|parse_options| parse_options.with_comment_prefix(Some("#")))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::Int64, DataType::String]);
+    assert_eq!(
+        Vec::from(df.column("a")?.i64()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(df.column("b")?.str()?),
+        &[Some("multi\n# not a comment"), Some("x # y"), Some("z")]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_null_values_argument() -> PolarsResult<()> {
     let csv = r"1,a,foo
@@ -821,6 +978,27 @@ fn test_automatic_datetime_parsing_default_formats() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "temporal")]
+fn test_automatic_date_parsing_mixed_formats() -> PolarsResult<()> {
+    let csv = r"date,mixed
+2021-01-01,2021-01-01
+2021-01-02,02-01-2021
+,2021-01-03
+";
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_try_parse_dates(true))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.dtypes(), &[DataType::Date, DataType::String]);
+    assert_eq!(df.column("date")?.null_count(), 1);
+    assert_eq!(df.column("mixed")?.null_count(), 0);
+    Ok(())
+}
+
 #[test]
 fn test_no_quotes() -> PolarsResult<()> {
     let rolling_stones = r#"linenum,last_name,first_name
@@ -1053,6 +1231,60 @@ fn test_null_values_infer_schema() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_null_values_multiple_and_named() -> PolarsResult<()> {
+    let csv = r#"a,b,c
+1,x,-
+"N/A",NULL,2.5
+3,-,NULL
+"#;
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_null_values(Some(NullValues::AllColumns(vec![
+                "NULL".into(),
+                "N/A".into(),
+                "-".into(),
+            ])))
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    // The null tokens are matched during inference as well, also when quoted.
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Int64, DataType::String, DataType::Float64]
+    );
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), None, Some(3)]);
+    assert_eq!(Vec::from(df.column("b")?.str()?), &[Some("x"), None, None]);
+    assert_eq!(Vec::from(df.column("c")?.f64()?), &[None, Some(2.5), None]);
+
+    // Per column null values only apply to their own column.
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options.with_null_values(Some(NullValues::Named(vec![
+                ("a".into(), "N/A".into()),
+                ("b".into(), "-".into()),
+                ("c".into(), "NULL".into()),
+            ])))
+        })
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::Int64, DataType::String, DataType::String]
+    );
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), None, Some(3)]);
+    assert_eq!(
+        Vec::from(df.column("b")?.str()?),
+        &[Some("x"), Some("NULL"), None]
+    );
+    assert_eq!(
+        Vec::from(df.column("c")?.str()?),
+        &[Some("-"), Some("2.5"), None]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_comma_separated_field_in_tsv() -> PolarsResult<()> {
     let csv = "first\tsecond\n1\t2.3,2.4\n3\t4.5,4.6\n";
@@ -1405,3 +1637,68 @@ fn test_read_io_reader() {
         .head(Some(df.height()));
     assert_eq!(&df, &expected);
 }
+
+#[test]
+#[cfg(all(feature = "dtype-u8", feature = "dtype-i16"))]
+fn test_small_int_schema_overwrite_round_trip() -> PolarsResult<()> {
+    let csv = "flag,delta\n1,-300\n0,12\n255,\n";
+    let file = Cursor::new(csv);
+    let mut df = CsvReadOptions::default()
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([
+            Field::new("flag".into(), DataType::UInt8),
+            Field::new("delta".into(), DataType::Int16),
+        ]))))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    assert_eq!(df.dtypes(), &[DataType::UInt8, DataType::Int16]);
+    assert_eq!(
+        Vec::from(df.column("flag")?.u8()?),
+        &[Some(1), Some(0), Some(255)]
+    );
+    assert_eq!(
+        Vec::from(df.column("delta")?.i16()?),
+        &[Some(-300), Some(12), None]
+    );
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf).finish(&mut df)?;
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), csv);
+    Ok(())
+}
+
+#[test]
+fn test_read_csv_string_buffer_capacity_is_bounded() -> PolarsResult<()> {
+    // A wide numeric file with a single text column. The text column is given a values capacity
+    // hint based on the bytes of the whole chunk, which must not be kept alive.
+    let n_numeric = 50;
+    let mut csv = (0..n_numeric).map(|i| format!("n{i},")).collect::<String>();
+    csv.push_str("text\n");
+    for row in 0..1000 {
+        for i in 0..n_numeric {
+            csv.push_str(&format!("{},", row * i));
+        }
+        csv.push_str("a string that is too long to be inlined\n");
+    }
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_n_threads(Some(1))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    let text = df.column("text")?.str()?.clone();
+    drop(df);
+
+    for arr in text.downcast_into_iter() {
+        let (_, mut buffers, _, _, total_buffer_len) = arr.into_inner();
+        let mut capacity = 0;
+        for buffer in Arc::get_mut(&mut buffers).unwrap() {
+            capacity += std::mem::take(buffer)
+                .into_mut()
+                .right()
+                .unwrap()
+                .capacity();
+        }
+        assert!(capacity <= 2 * total_buffer_len);
+    }
+    Ok(())
+}
@@ -155,3 +155,72 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[cfg(feature = "dtype-categorical")]
+fn create_nested_df() -> DataFrame {
+    let mut df = df![
+        "bool" => [Some(true), None, Some(false), Some(true)],
+        "i64" => [Some(1i64), Some(2), None, Some(4)],
+        "u32" => [Some(1u32), None, Some(3), Some(4)],
+        "f32" => [Some(1.5f32), Some(2.5), Some(3.5), None],
+        "str" => [Some("a"), None, Some("ccc"), Some("")],
+        "list" => [
+            Some(Series::new("".into(), [1i32, 2])),
+            None,
+            Some(Series::new("".into(), [Some(3i32), None])),
+            Some(Series::new_empty("".into(), &DataType::Int32)),
+        ],
+        "cat" => [Some("x"), Some("y"), None, Some("x")],
+    ]
+    .unwrap();
+    df.try_apply("cat", |s| {
+        s.cast(&DataType::from_categories(Categories::global()))
+    })
+    .unwrap();
+    df
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn write_and_read_ipc_nested_and_categorical() -> PolarsResult<()> {
+    let mut df = create_nested_df();
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut df)?;
+
+    buf.set_position(0);
+    let df_read = IpcReader::new(&mut buf).finish()?;
+    assert_eq!(df_read.schema(), df.schema());
+    assert!(df_read.equals_missing(&df));
+
+    buf.set_position(0);
+    let df_read = IpcReader::new(buf)
+        .with_columns(Some(vec!["list".to_string(), "cat".to_string()]))
+        .with_n_rows(Some(2))
+        .finish()?;
+    let expected = df.select(["list", "cat"])?.head(Some(2));
+    assert!(df_read.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn read_ipc_memory_mapped() -> PolarsResult<()> {
+    let mut df = create_nested_df();
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("read_ipc_memory_mapped.ipc");
+
+    let mut file = std::fs::File::create(&path)?;
+    IpcWriter::new(&mut file).finish(&mut df)?;
+    drop(file);
+
+    let mapped = IpcReader::new(std::fs::File::open(&path)?)
+        .memory_mapped(Some(path.clone()))
+        .finish()?;
+    let copied = IpcReader::new(std::fs::File::open(&path)?).finish()?;
+
+    assert!(mapped.equals_missing(&copied));
+    assert!(mapped.equals_missing(&df));
+    drop(mapped);
+    Ok(())
+}
@@ -185,3 +185,40 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_ndjson_ragged_records_and_roundtrip() -> PolarsResult<()> {
+    // Keys missing on some lines are filled with nulls.
+    let jsonlines = "{\"a\":1}\n{\"a\":2,\"b\":\"x\"}\n{\"b\":\"y\"}\n";
+    let df = JsonLineReader::new(Cursor::new(jsonlines)).finish()?;
+    assert_eq!(df.get_column_names_str(), &["a", "b"]);
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), Some(2), None]);
+    assert_eq!(
+        Vec::from(df.column("b")?.str()?),
+        &[None, Some("x"), Some("y")]
+    );
+
+    // Writing as json lines emits one object per row and lists as arrays.
+    let mut df = DataFrame::new(vec![
+        Column::new("a".into(), [1i64, 2]),
+        Column::new(
+            "b".into(),
+            [
+                Series::new("".into(), [1i64, 2]),
+                Series::new("".into(), [3i64]),
+            ],
+        ),
+        Column::new("c".into(), [Some("x"), None]),
+    ])?;
+    let mut buf = Vec::new();
+    JsonWriter::new(&mut buf)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut df)?;
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(out.lines().count(), 2);
+    assert!(out.lines().next().unwrap().contains("\"b\":[1,2]"));
+
+    let read = JsonLineReader::new(Cursor::new(out)).finish()?;
+    assert!(read.equals_missing(&df));
+    Ok(())
+}
@@ -297,3 +297,90 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_parquet_round_trip_compression_row_groups() -> PolarsResult<()> {
+    use polars::io::parquet::write::ParquetCompression;
+
+    let mut df = df![
+        "bool" => [Some(true), None, Some(false), Some(true), None],
+        "i32" => [Some(1i32), Some(2), None, Some(4), Some(5)],
+        "i64" => [Some(1i64), None, Some(3), Some(4), Some(5)],
+        "u32" => [Some(1u32), Some(2), Some(3), None, Some(5)],
+        "f64" => [Some(1.5f64), Some(2.5), None, Some(4.5), Some(-0.5)],
+        "str" => [Some("a"), None, Some("ccc"), Some(""), Some("e")],
+        "list" => [
+            Some(Series::new("".into(), [1i64, 2])),
+            None,
+            Some(Series::new("".into(), [3i64])),
+            Some(Series::new_empty("".into(), &DataType::Int64)),
+            Some(Series::new("".into(), [Some(4i64), None])),
+        ],
+        "cat" => [Some("x"), Some("y"), None, Some("x"), Some("z")],
+    ]?;
+    df.try_apply("cat", |s| {
+        s.cast(&DataType::from_categories(Categories::global()))
+    })?;
+
+    for compression in [
+        ParquetCompression::Uncompressed,
+        ParquetCompression::Snappy,
+        ParquetCompression::Gzip(None),
+        ParquetCompression::Zstd(None),
+    ] {
+        let mut buf = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_compression(compression)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+
+        buf.set_position(0);
+        let metadata = read_metadata(&mut buf)?;
+        assert_eq!(metadata.row_groups.len(), 2);
+        assert_eq!(
+            metadata
+                .row_groups
+                .iter()
+                .map(|rg| rg.num_rows())
+                .sum::<usize>(),
+            df.height()
+        );
+
+        buf.set_position(0);
+        let read = ParquetReader::new(buf).finish()?;
+        assert_eq!(read.schema(), df.schema());
+        assert!(read.equals_missing(&df));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_read_parquet_with_columns_and_n_rows() -> PolarsResult<()> {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..10).collect::<Vec<i32>>(),
+        "b" => (10..20).collect::<Vec<i32>>(),
+        "c" => (20..30).collect::<Vec<i32>>()
+    )?;
+
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(2))
+        .finish(&mut df)?;
+    buf.set_position(0);
+
+    let df_read = ParquetReader::new(buf)
+        .with_columns(Some(vec!["a".to_string(), "c".to_string()]))
+        .with_n_rows(Some(3))
+        .finish()?;
+    assert_eq!(df_read.get_column_names_str(), &["a", "c"]);
+    assert_eq!(
+        Vec::from(df_read.column("a")?.i32()?),
+        &[Some(0), Some(1), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(df_read.column("c")?.i32()?),
+        &[Some(20), Some(21), Some(22)]
+    );
+    Ok(())
+}
@@ -18,3 +18,39 @@ fn test_explode_row_numbers() -> PolarsResult<()> {
     assert_eq!(df.shape(), (8, 2));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_explode_categorical_list() -> PolarsResult<()> {
+    let cat = DataType::from_categories(Categories::global());
+    let df = df![
+        "id" => [1, 2],
+        "tags" => [
+            Series::new("".into(), &["a", "b"]),
+            Series::new("".into(), &["b", "c", "b"]),
+        ],
+    ]?
+    .lazy()
+    .with_column(col("tags").cast(DataType::List(Box::new(cat))))
+    .explode(cols(["tags"]));
+
+    let exploded = df.clone().collect()?;
+    assert!(matches!(
+        exploded.column("tags")?.dtype(),
+        DataType::Categorical(_, _)
+    ));
+
+    let out = df
+        .group_by([col("tags")])
+        .agg([col("id").count().alias("n")])
+        .with_column(col("tags").cast(DataType::String))
+        .sort(["tags"], Default::default())
+        .collect()?;
+
+    let expected = df![
+        "tags" => ["a", "b", "c"],
+        "n" => [1 as IdxSize, 3, 1],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -367,3 +367,38 @@ fn test_binary_group_consistency() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_when_then_chained_supertype() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), Some(3), Some(6), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            when(col("a").gt(lit(5)))
+                .then(lit("big"))
+                .when(col("a").gt(lit(2)))
+                .then(lit("mid"))
+                .otherwise(lit("small"))
+                .alias("size"),
+            when(col("a").gt(lit(2)))
+                .then(col("a"))
+                .otherwise(lit(0.5))
+                .alias("mixed"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("size")?.str()?),
+        &[Some("small"), Some("mid"), Some("big"), Some("small")]
+    );
+    // i32 and f64 branches unify to f64.
+    assert_eq!(out.column("mixed")?.dtype(), &DataType::Float64);
+    assert_eq!(
+        Vec::from(out.column("mixed")?.f64()?),
+        &[Some(0.5), Some(3.0), Some(6.0), Some(0.5)]
+    );
+    Ok(())
+}
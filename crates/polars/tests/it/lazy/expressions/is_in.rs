@@ -18,3 +18,50 @@ fn test_is_in() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_is_in_supertype_nulls_and_lists() -> PolarsResult<()> {
+    let df = df![
+        "x" => [Some(1i32), Some(2), None, Some(4)],
+        "allowed" => [
+            Series::new("".into(), [1i32, 5]),
+            Series::new("".into(), [3i32]),
+            Series::new("".into(), [1i32]),
+            Series::new("".into(), [4i32, 4])
+        ]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            // The i32 values are compared against an i64 set.
+            col("x")
+                .is_in(
+                    lit(Series::new("".into(), [Some(2i64), Some(4), None])),
+                    false,
+                )
+                .alias("in_set"),
+            col("x")
+                .is_in(lit(Series::new("".into(), [Some(2i64), None])), true)
+                .alias("nulls_equal"),
+            // Every row is tested against its own list.
+            col("x").is_in(col("allowed"), false).alias("in_row"),
+        ])
+        .collect()?;
+
+    // A null value gives a null result and a null in the set matches nothing,
+    // unless nulls are considered equal.
+    assert_eq!(
+        Vec::from(out.column("in_set")?.bool()?),
+        &[Some(false), Some(true), None, Some(true)]
+    );
+    assert_eq!(
+        Vec::from(out.column("nulls_equal")?.bool()?),
+        &[Some(false), Some(true), Some(true), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("in_row")?.bool()?),
+        &[Some(true), Some(false), None, Some(true)]
+    );
+    Ok(())
+}
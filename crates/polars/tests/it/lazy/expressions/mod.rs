@@ -6,6 +6,8 @@ mod filter;
 mod is_in;
 mod literals;
 mod slice;
+#[cfg(feature = "strings")]
+mod strings;
 mod window;
 
 use super::*;
@@ -0,0 +1,284 @@
+use super::*;
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_str_contains_and_extract() -> PolarsResult<()> {
+    let df = df![
+        "line" => [
+            Some("2024-01-02 ERROR [db] timeout after 30s"),
+            Some("2024-01-02 INFO [web] GET /index 200"),
+            None,
+            Some("no timestamp ERROR"),
+        ]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("line")
+                .str()
+                .contains(lit(r"ERROR|WARN"), true)
+                .alias("is_error"),
+            col("line")
+                .str()
+                .contains_literal(lit("[db]"))
+                .alias("is_db"),
+            col("line").str().starts_with(lit("2024")).alias("dated"),
+            col("line").str().ends_with(lit("200")).alias("ok"),
+            col("line")
+                .str()
+                .extract(lit(r"\[(\w+)\]"), 1)
+                .alias("component"),
+            col("line")
+                .str()
+                .count_matches(lit(r"\d+"), false)
+                .alias("numbers"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("is_error")?.bool()?),
+        &[Some(true), Some(false), None, Some(true)]
+    );
+    assert_eq!(
+        Vec::from(out.column("is_db")?.bool()?),
+        &[Some(true), Some(false), None, Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("dated")?.bool()?),
+        &[Some(true), Some(true), None, Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("ok")?.bool()?),
+        &[Some(false), Some(true), None, Some(false)]
+    );
+    // No match is null, as is a null input.
+    assert_eq!(
+        Vec::from(out.column("component")?.str()?),
+        &[Some("db"), Some("web"), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("numbers")?.u32()?),
+        &[Some(4), Some(4), None, Some(0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_str_replace_groups_and_literals() -> PolarsResult<()> {
+    let df = df![
+        "name" => [Some("John Smith"), Some(""), None, Some("Ada Lovelace")]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("name")
+                .str()
+                .replace(lit(r"(\w+) (\w+)"), lit("$2, $1"), false)
+                .alias("regex"),
+            // Capture group references are not expanded in literal mode.
+            col("name")
+                .str()
+                .replace(lit("a"), lit("$1"), true)
+                .alias("first"),
+            col("name")
+                .str()
+                .replace_all(lit("a"), lit("$1"), true)
+                .alias("all"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("regex")?.str()?),
+        &[Some("Smith, John"), Some(""), None, Some("Lovelace, Ada")]
+    );
+    assert_eq!(
+        Vec::from(out.column("first")?.str()?),
+        &[Some("John Smith"), Some(""), None, Some("Ad$1 Lovelace")]
+    );
+    assert_eq!(
+        Vec::from(out.column("all")?.str()?),
+        &[Some("John Smith"), Some(""), None, Some("Ad$1 Lovel$1ce")]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_str_replace_empty_matches() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("abc"), Some(""), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s")
+                .str()
+                .replace(lit(""), lit("-"), true)
+                .alias("empty_first"),
+            col("s")
+                .str()
+                .replace_all(lit(""), lit("-"), true)
+                .alias("empty_all"),
+            col("s")
+                .str()
+                .replace_all(lit("x*"), lit("-"), false)
+                .alias("matches_empty"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("empty_first")?.str()?),
+        &[Some("-abc"), Some("-"), None]
+    );
+    for name in ["empty_all", "matches_empty"] {
+        assert_eq!(
+            Vec::from(out.column(name)?.str()?),
+            &[Some("-a-b-c-"), Some("-"), None]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_str_case_and_strip() -> PolarsResult<()> {
+    let df = df![
+        "key" => [Some("  Straße\u{3000}"), Some("xxΣΑΣxx"), Some("\tİstanbul\n"), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("key")
+                .str()
+                .strip_chars(lit(NULL))
+                .str()
+                .to_uppercase()
+                .alias("upper"),
+            col("key")
+                .str()
+                .strip_chars(lit("x"))
+                .str()
+                .to_lowercase()
+                .alias("lower"),
+            col("key").str().strip_chars_start(lit(NULL)).alias("start"),
+            col("key")
+                .str()
+                .strip_chars_end(lit(" \u{3000}"))
+                .alias("end"),
+        ])
+        .collect()?;
+
+    // Stripping defaults to unicode whitespace and casing is not limited to ascii.
+    assert_eq!(
+        Vec::from(out.column("upper")?.str()?),
+        &[Some("STRASSE"), Some("XXΣΑΣXX"), Some("İSTANBUL"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("lower")?.str()?),
+        &[
+            Some("  straße\u{3000}"),
+            Some("σας"),
+            Some("\ti\u{307}stanbul\n"),
+            None
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("start")?.str()?),
+        &[
+            Some("Straße\u{3000}"),
+            Some("xxΣΑΣxx"),
+            Some("İstanbul\n"),
+            None
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("end")?.str()?),
+        &[
+            Some("  Straße"),
+            Some("xxΣΑΣxx"),
+            Some("\tİstanbul\n"),
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_str_lengths_and_slice() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("hello"), Some("héllö"), Some("日本語"), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s").str().len_bytes().alias("bytes"),
+            col("s").str().len_chars().alias("chars"),
+            col("s").str().slice(lit(1), lit(2)).alias("mid"),
+            col("s").str().slice(lit(-2), lit(NULL)).alias("last"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("bytes")?.u32()?),
+        &[Some(5), Some(7), Some(9), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("chars")?.u32()?),
+        &[Some(5), Some(5), Some(3), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("mid")?.str()?),
+        &[Some("el"), Some("él"), Some("本語"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("last")?.str()?),
+        &[Some("lo"), Some("lö"), Some("本語"), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "concat_str")]
+fn test_concat_str_and_format_str() -> PolarsResult<()> {
+    let df = df![
+        "x" => [Some(1i32), None, Some(3)],
+        "y" => [Some(0.5f64), Some(1.5), None],
+        "label" => [Some("a"), Some("b"), Some("c")],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            concat_str([col("label"), col("x"), col("y")], "_", false).alias("strict"),
+            concat_str([col("label"), col("x"), col("y")], "_", true).alias("skip"),
+            concat_str([lit("id"), col("x")], ":", true).alias("with_lit"),
+            format_str("({}, {})", [col("x"), col("y")])?.alias("point"),
+        ])
+        .collect()?;
+
+    // A null input makes the row null unless nulls are skipped.
+    assert_eq!(
+        Vec::from(out.column("strict")?.str()?),
+        &[Some("a_1_0.5"), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("skip")?.str()?),
+        &[Some("a_1_0.5"), Some("b_1.5"), Some("c_3")]
+    );
+    assert_eq!(
+        Vec::from(out.column("with_lit")?.str()?),
+        &[Some("id:1"), Some("id"), Some("id:3")]
+    );
+    assert_eq!(
+        Vec::from(out.column("point")?.str()?),
+        &[Some("(1, 0.5)"), Some("(null, 1.5)"), Some("(3, null)")]
+    );
+
+    assert!(format_str("({}, {})", [col("x")]).is_err());
+    Ok(())
+}
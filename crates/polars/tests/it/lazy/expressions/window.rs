@@ -393,3 +393,103 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+fn test_window_aggregations_keep_row_order() -> PolarsResult<()> {
+    let df = df![
+        "store" => ["a", "b", "a", "c", "b", "a"],
+        "sales" => [1, 2, 3, 4, 5, 6]
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_columns([
+            col("sales").sum().over([col("store")]).alias("sum"),
+            col("sales").mean().over([col("store")]).alias("mean"),
+            col("sales").min().over([col("store")]).alias("min"),
+            col("sales").max().over([col("store")]).alias("max"),
+            col("sales").count().over([col("store")]).alias("count"),
+            col("sales").first().over([col("store")]).alias("first"),
+            col("sales").last().over([col("store")]).alias("last"),
+        ])
+        .collect()?;
+
+    let values =
+        |name: &str| -> PolarsResult<Vec<Option<i32>>> { Ok(Vec::from(out.column(name)?.i32()?)) };
+    assert_eq!(
+        values("sum")?,
+        &[Some(10), Some(7), Some(10), Some(4), Some(7), Some(10)]
+    );
+    assert_eq!(
+        values("min")?,
+        &[Some(1), Some(2), Some(1), Some(4), Some(2), Some(1)]
+    );
+    assert_eq!(
+        values("max")?,
+        &[Some(6), Some(5), Some(6), Some(4), Some(5), Some(6)]
+    );
+    assert_eq!(values("first")?, values("min")?);
+    assert_eq!(values("last")?, values("max")?);
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[Some(3), Some(2), Some(3), Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[
+            Some(10.0 / 3.0),
+            Some(3.5),
+            Some(10.0 / 3.0),
+            Some(4.0),
+            Some(3.5),
+            Some(10.0 / 3.0)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rank")]
+fn test_rank_over_groups() -> PolarsResult<()> {
+    let df = df![
+        "category" => ["a", "b", "a", "b", "a", "b"],
+        "sales" => [Some(10), Some(5), Some(30), None, Some(10), Some(7)]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("sales")
+                .rank(
+                    RankOptions {
+                        method: RankMethod::Dense,
+                        descending: true,
+                    },
+                    None,
+                )
+                .over([col("category")])
+                .alias("dense"),
+            col("sales")
+                .rank(
+                    RankOptions {
+                        method: RankMethod::Average,
+                        descending: false,
+                    },
+                    None,
+                )
+                .over([col("category")])
+                .alias("average"),
+        ])
+        .collect()?;
+
+    // Ranks restart in every group, nulls keep a null rank.
+    assert_eq!(
+        Vec::from(out.column("dense")?.idx()?),
+        &[Some(2), Some(2), Some(1), None, Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("average")?.f64()?),
+        &[Some(1.5), Some(1.0), Some(3.0), None, Some(1.5), Some(2.0)]
+    );
+    Ok(())
+}
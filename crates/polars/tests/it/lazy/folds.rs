@@ -34,3 +34,50 @@ fn test_fold_wildcard() -> PolarsResult<()> {
         .collect()?;
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_fold_regex_selection() -> PolarsResult<()> {
+    let df = df![
+        "sales_a" => [1, 2, 3],
+        "sales_b" => [10, 20, 30],
+        "other" => [100, 200, 300],
+        "flag_a" => [false, true, false],
+        "flag_b" => [false, false, true]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            fold_exprs(
+                lit(0),
+                PlanCallback::new(|(a, b)| &a + &b),
+                [col("^sales_.*$")],
+                false,
+                Some(DataType::Int32.into()),
+            )
+            .alias("fold"),
+            polars_lazy::dsl::sum_horizontal([col("^sales_.*$")], true)?.alias("sum"),
+            polars_lazy::dsl::any_horizontal([col("^flag_.*$")])?.alias("any"),
+            polars_lazy::dsl::all_horizontal([col("^flag_.*$")])?.alias("all"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("fold")?.i32()?),
+        &[Some(11), Some(22), Some(33)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(11), Some(22), Some(33)]
+    );
+    assert_eq!(
+        Vec::from(out.column("any")?.bool()?),
+        &[Some(false), Some(true), Some(true)]
+    );
+    assert_eq!(
+        Vec::from(out.column("all")?.bool()?),
+        &[Some(false), Some(false), Some(false)]
+    );
+    Ok(())
+}
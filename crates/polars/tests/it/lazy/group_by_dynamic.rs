@@ -61,3 +61,135 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-date",
+    feature = "dynamic_group_by"
+))]
+fn test_group_by_dynamic_tumbling_hopping_and_keys() -> PolarsResult<()> {
+    let start = NaiveDate::from_ymd_opt(2022, 2, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 2, 1)
+        .unwrap()
+        .and_hms_opt(1, 30, 0)
+        .unwrap();
+    let range = polars_time::date_range(
+        "dt".into(),
+        start,
+        stop,
+        Duration::parse("30m"),
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        None,
+    )?
+    .into_series();
+
+    let df = df![
+        "dt" => range,
+        "g" => ["a", "b", "a", "b"],
+        "v" => [1, 2, 3, 4]
+    ]?;
+
+    let sums = |every: &str, period: &str, by_key: bool| -> PolarsResult<Vec<i32>> {
+        let group_by = if by_key { vec![col("g")] } else { vec![] };
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_dynamic(
+                col("dt"),
+                group_by,
+                DynamicGroupOptions {
+                    every: Duration::parse(every),
+                    period: Duration::parse(period),
+                    offset: Duration::parse("0m"),
+                    ..Default::default()
+                },
+            )
+            .agg([col("v").sum()])
+            .collect()?;
+        let out = if by_key {
+            out.sort(["g", "dt"], Default::default())?
+        } else {
+            out
+        };
+        Ok(out.column("v")?.i32()?.into_no_null_iter().collect())
+    };
+
+    // Tumbling windows: [00:00, 01:00) and [01:00, 02:00).
+    assert_eq!(sums("1h", "1h", false)?, &[3, 7]);
+    // Hopping windows of one hour, starting every 30 minutes.
+    assert_eq!(sums("30m", "1h", false)?, &[3, 5, 7, 4]);
+    // One row per key and window.
+    assert_eq!(sums("1h", "1h", true)?, &[1, 3, 2, 4]);
+
+    // The index column has to be sorted.
+    let unsorted = df.sort(
+        ["v"],
+        SortMultipleOptions::default().with_order_descending(true),
+    )?;
+    let out = unsorted
+        .lazy()
+        .group_by_dynamic(
+            col("dt"),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse("1h"),
+                period: Duration::parse("1h"),
+                offset: Duration::parse("0m"),
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_rolling_closed_windows_and_keys() -> PolarsResult<()> {
+    let df = df![
+        "t" => [0i64, 2, 3, 5, 9],
+        "sym" => ["a", "b", "a", "b", "a"],
+        "v" => [1, 1, 1, 1, 1]
+    ]?;
+
+    let counts = |closed_window: ClosedWindow, by_key: bool| -> PolarsResult<Vec<IdxSize>> {
+        let group_by = if by_key { vec![col("sym")] } else { vec![] };
+        let out = df
+            .clone()
+            .lazy()
+            .rolling(
+                col("t"),
+                group_by,
+                RollingGroupOptions {
+                    period: Duration::parse("3i"),
+                    offset: Duration::parse("-3i"),
+                    closed_window,
+                    ..Default::default()
+                },
+            )
+            .agg([col("v").count().alias("n")])
+            .collect()?;
+        let out = if by_key {
+            out.sort(["sym", "t"], Default::default())?
+        } else {
+            out
+        };
+        Ok(out.column("n")?.idx()?.into_no_null_iter().collect())
+    };
+
+    // Every row looks back three units from its own index value.
+    assert_eq!(counts(ClosedWindow::Right, false)?, &[1, 2, 2, 2, 1]);
+    assert_eq!(counts(ClosedWindow::Left, false)?, &[0, 1, 2, 2, 0]);
+    assert_eq!(counts(ClosedWindow::Both, false)?, &[1, 2, 3, 3, 1]);
+    assert_eq!(counts(ClosedWindow::None, false)?, &[0, 1, 1, 1, 0]);
+
+    // With keys the windows only contain rows of the same key.
+    assert_eq!(counts(ClosedWindow::Both, true)?, &[1, 2, 1, 1, 2]);
+    Ok(())
+}
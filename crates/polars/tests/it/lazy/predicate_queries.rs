@@ -263,3 +263,21 @@ fn test_predicate_pushdown_block_8847() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_predicate_pushdown_blocked_by_row_index() -> PolarsResult<()> {
+    let out = df![
+        "a" => [1, 2, 3, 4]
+    ]?
+    .lazy()
+    .with_row_index("idx", Some(10))
+    .filter(col("a").gt(lit(2)))
+    .collect()?;
+
+    let expected = df![
+        "idx" => [12 as IdxSize, 13],
+        "a" => [3, 4],
+    ]?;
+    assert_eq!(out, expected);
+    Ok(())
+}
@@ -42,3 +42,75 @@ fn test_datetime_parse_overflow_7631() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_strptime_fractional_seconds_and_strftime() -> PolarsResult<()> {
+    let df = df![
+        "ts" => [Some("2021-01-01 07:45:12.123456"), Some("2021-01-02 00:00:00.5"), None],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("ts").str().to_datetime(
+            Some(TimeUnit::Microseconds),
+            None,
+            StrptimeOptions {
+                format: Some("%Y-%m-%d %H:%M:%S%.f".into()),
+                ..Default::default()
+            },
+            lit("raise"),
+        )])
+        .with_column(
+            col("ts")
+                .dt()
+                .to_string("%Y-%m-%d %H:%M:%S%.6f")
+                .alias("formatted"),
+        )
+        .collect()?;
+
+    assert_eq!(
+        out.column("ts")?.get(0)?,
+        AnyValue::Datetime(1609487112123456, TimeUnit::Microseconds, None)
+    );
+    assert_eq!(
+        out.column("ts")?.get(1)?,
+        AnyValue::Datetime(1609545600500000, TimeUnit::Microseconds, None)
+    );
+    assert_eq!(
+        Vec::from(out.column("formatted")?.str()?),
+        &[
+            Some("2021-01-01 07:45:12.123456"),
+            Some("2021-01-02 00:00:00.500000"),
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_strptime_does_not_partially_parse() -> PolarsResult<()> {
+    let df = df![
+        "d" => ["2021-01-01", "2021-01-02 extra"],
+    ]?;
+    let parse = |strict| {
+        df.clone()
+            .lazy()
+            .select([col("d").str().to_date(StrptimeOptions {
+                format: Some("%Y-%m-%d".into()),
+                strict,
+                ..Default::default()
+            })])
+            .collect()
+    };
+
+    let err = parse(true).unwrap_err().to_string();
+    assert!(err.contains("2021-01-02 extra"), "{err}");
+    assert!(err.contains("first failure at row 1"), "{err}");
+
+    let out = parse(false)?;
+    assert_eq!(
+        Vec::from(out.column("d")?.to_physical_repr().i32()?),
+        &[Some(18628), None]
+    );
+    Ok(())
+}
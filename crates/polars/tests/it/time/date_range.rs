@@ -78,3 +78,80 @@ Series: 'date' [datetime[ns]]
     assert_eq!(result, expected);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_date_range_closed_and_invalid_interval() -> PolarsResult<()> {
+    let start = NaiveDate::from_ymd_opt(2022, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 1, 1)
+        .unwrap()
+        .and_hms_opt(4, 30, 0)
+        .unwrap();
+    let range = |start, stop, every, closed| {
+        date_range(
+            "date".into(),
+            start,
+            stop,
+            Duration::parse(every),
+            closed,
+            TimeUnit::Milliseconds,
+            None,
+        )
+    };
+    let minutes = |ca: DatetimeChunked| -> Vec<i64> {
+        ca.physical()
+            .into_no_null_iter()
+            .map(|ms| ms / 60_000)
+            .collect()
+    };
+
+    let start_min = start.and_utc().timestamp() / 60;
+    let expected = |mins: &[i64]| mins.iter().map(|m| start_min + m).collect::<Vec<_>>();
+    assert_eq!(
+        minutes(range(start, stop, "1h30m", ClosedWindow::Both)?),
+        expected(&[0, 90, 180, 270])
+    );
+    assert_eq!(
+        minutes(range(start, stop, "1h30m", ClosedWindow::Left)?),
+        expected(&[0, 90, 180])
+    );
+    assert_eq!(
+        minutes(range(start, stop, "1h30m", ClosedWindow::Right)?),
+        expected(&[90, 180, 270])
+    );
+    assert_eq!(
+        minutes(range(start, stop, "1h30m", ClosedWindow::None)?),
+        expected(&[90, 180])
+    );
+
+    assert!(range(stop, start, "15m", ClosedWindow::Both).is_err());
+    assert!(range(start, stop, "0h", ClosedWindow::Both).is_err());
+    assert!(range(start, stop, "-1h", ClosedWindow::Both).is_err());
+    assert!(range(stop, start, "0h", ClosedWindow::Both).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "range", feature = "dtype-date"))]
+fn test_date_range_expr_start_after_end() -> PolarsResult<()> {
+    let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+    let stop = NaiveDate::from_ymd_opt(2022, 1, 5).unwrap();
+    let range = |start: NaiveDate, stop: NaiveDate| {
+        df!["x" => [0]]?
+            .lazy()
+            .select([polars::lazy::dsl::date_range(
+                lit(start),
+                lit(stop),
+                Duration::parse("1d"),
+                ClosedWindow::Both,
+            )])
+            .collect()
+    };
+
+    assert_eq!(range(start, stop)?.height(), 5);
+    // The expression errors just like the eager `date_range`.
+    assert!(range(stop, start).is_err());
+    Ok(())
+}